@@ -0,0 +1,287 @@
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Registry;
+use crate::report::Status;
+use crate::runner::{Runner, RunnerError, Sampling};
+
+/// Summary statistics over the timed samples of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "stddev_ns", with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let count = u32::try_from(n).ok().filter(|&count| count > 0)?;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        let mean = sorted.iter().sum::<Duration>() / count;
+        let stddev = if count > 1 {
+            let mean = mean.as_secs_f64();
+            let variance = sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / f64::from(count - 1);
+            Duration::from_secs_f64(variance.sqrt())
+        } else {
+            Duration::ZERO
+        };
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            stddev,
+        })
+    }
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(duration.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    day: u16,
+    part: u16,
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+/// Benchmark results saved by one run, to compare later runs against.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn load(filename: &Path) -> Result<Self, RunnerError> {
+        let json = std::fs::read_to_string(filename).map_err(RunnerError::io(filename))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, filename: &Path) -> Result<(), RunnerError> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(filename, json).map_err(RunnerError::io(filename))
+    }
+
+    /// Replaces the entries of the parts measured again, keeping those of the other parts.
+    pub fn merge(&mut self, other: Self) {
+        self.entries
+            .retain(|entry| other.get(entry.day, entry.part).is_none());
+        self.entries.extend(other.entries);
+        self.entries.sort_by_key(|entry| (entry.day, entry.part));
+    }
+
+    fn get(&self, day: u16, part: u16) -> Option<&Stats> {
+        self.entries
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part)
+            .map(|entry| &entry.stats)
+    }
+}
+
+/// How the median of a part moved relative to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    /// Not present in the baseline
+    New,
+    /// Relative change in percent, within the threshold
+    Unchanged(f64),
+    Faster(f64),
+    Slower(f64),
+}
+
+impl Comparison {
+    /// Compares medians, flagging changes larger than `threshold` percent.
+    pub fn new(current: &Stats, baseline: Option<&Stats>, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Self::New;
+        };
+        let before = baseline.median.as_secs_f64();
+        let after = current.median.as_secs_f64();
+        if before == 0.0 {
+            return Self::Unchanged(0.0);
+        }
+        let change = (after - before) / before * 100.0;
+        if change > threshold {
+            Self::Slower(change)
+        } else if change < -threshold {
+            Self::Faster(change)
+        } else {
+            Self::Unchanged(change)
+        }
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::New => write!(f, "new"),
+            Self::Unchanged(change) => write!(f, "{change:+.1}%"),
+            Self::Faster(change) => write!(f, "{change:+.1}% faster"),
+            Self::Slower(change) => write!(f, "{change:+.1}% SLOWER"),
+        }
+    }
+}
+
+/// Outcome of a benchmark run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchSummary {
+    pub results: Baseline,
+    /// Parts slower than the baseline by more than the threshold
+    pub regressions: usize,
+}
+
+impl Registry {
+    pub fn bench(
+        &self,
+        runner: &mut Runner,
+        day_filter: Option<u16>,
+        part_filter: Option<u16>,
+        sampling: Sampling,
+        baseline: Option<&Baseline>,
+        threshold: f64,
+    ) -> Result<BenchSummary, RunnerError> {
+        let mut summary = BenchSummary::default();
+        for entry in self.days {
+            if day_filter.is_some_and(|d| d != entry.day) {
+                continue;
+            }
+            for &part in entry.parts {
+                if part_filter.is_some_and(|p| p != part) {
+                    continue;
+                }
+                let day = entry.day;
+                let report = entry.run(runner, part, sampling)?;
+                let stats = Stats::from_samples(&report.sample_times);
+                let (Status::Ok, Some(stats)) = (report.status, stats) else {
+                    println!(
+                        "Quest {day} - Part {part}: skipped ({})",
+                        report.status.as_str()
+                    );
+                    continue;
+                };
+                print!(
+                    "Quest {day} - Part {part}: min {:?}, median {:?}, mean {:?}, p95 {:?}, stddev {:?}",
+                    stats.min, stats.median, stats.mean, stats.p95, stats.stddev
+                );
+                if let Some(baseline) = baseline {
+                    let comparison = Comparison::new(&stats, baseline.get(day, part), threshold);
+                    if matches!(comparison, Comparison::Slower(_)) {
+                        summary.regressions += 1;
+                    }
+                    println!(" ({comparison})");
+                } else {
+                    println!();
+                }
+                summary
+                    .results
+                    .entries
+                    .push(BaselineEntry { day, part, stats });
+            }
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[9, 2, 5, 4, 12, 7, 4, 5])).unwrap();
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(6));
+        assert_eq!(stats.p95, Duration::from_micros(12));
+        assert_eq!(stats.stddev.as_nanos(), 3_207);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let stats = |median| Stats::from_samples(&micros(&[median])).unwrap();
+        assert_eq!(Comparison::new(&stats(100), None, 10.0), Comparison::New);
+        assert!(matches!(
+            Comparison::new(&stats(105), Some(&stats(100)), 10.0),
+            Comparison::Unchanged(_)
+        ));
+        assert!(matches!(
+            Comparison::new(&stats(120), Some(&stats(100)), 10.0),
+            Comparison::Slower(_)
+        ));
+        assert!(matches!(
+            Comparison::new(&stats(50), Some(&stats(100)), 10.0),
+            Comparison::Faster(_)
+        ));
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let baseline = Baseline {
+            entries: vec![BaselineEntry {
+                day: 3,
+                part: 2,
+                stats: Stats::from_samples(&micros(&[1, 2, 3])).unwrap(),
+            }],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_baseline_merge() {
+        let entry = |day, part, median| BaselineEntry {
+            day,
+            part,
+            stats: Stats::from_samples(&micros(&[median])).unwrap(),
+        };
+        let mut baseline = Baseline {
+            entries: vec![entry(1, 1, 10), entry(3, 2, 20)],
+        };
+        baseline.merge(Baseline {
+            entries: vec![entry(3, 2, 30), entry(2, 1, 40)],
+        });
+        assert_eq!(
+            baseline.entries,
+            [entry(1, 1, 10), entry(2, 1, 40), entry(3, 2, 30)]
+        );
+    }
+}
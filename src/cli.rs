@@ -0,0 +1,508 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser};
+use clap_derive::Subcommand;
+use ureq::http::Uri;
+
+use crate::bench::Baseline;
+use crate::params::parse_pair;
+use crate::report::{Format, Reporter};
+use crate::runner::{
+    DEFAULT_BASE_URL, Event, Runner, RunnerError, Sampling, install_panic_hook, read_input_file,
+};
+use crate::{REGISTRY, memory, scaffold};
+
+#[derive(Parser)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Cli {
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+    pub day: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
+    pub part: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: Option<u32>,
+    /// Read the input of the chosen day and part from a file, or `-` for stdin
+    #[arg(short, long, requires_all = ["day", "part"])]
+    pub input: Option<PathBuf>,
+    /// Run the solutions on the puzzle examples, and check the answers. Each example brings its
+    /// own parameters, and is run once.
+    #[arg(long, conflicts_with_all = ["input", "format", "parallel", "repeat", "mem", "params"])]
+    pub example: bool,
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Run the parts in parallel. Timings are then measured under contention.
+    #[arg(short = 'j', long)]
+    pub parallel: bool,
+    /// Count the allocations, bytes allocated and peak memory use of parsing and each part.
+    /// Needs a build with the `mem` feature.
+    #[arg(long, conflicts_with_all = ["parallel", "timeout"])]
+    pub mem: bool,
+    /// Override a parameter of the quest, such as the number of rounds. May be repeated.
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_pair, requires = "day")]
+    pub params: Vec<(String, String)>,
+    /// Event to use: a year such as `2025`, or a story such as `story-1`
+    #[arg(short, long, global = true, default_value_t = Event::Main(2025))]
+    pub event: Event,
+    #[arg(long, global = true, default_value = DEFAULT_BASE_URL, value_parser = parse_base_url)]
+    pub base_url: String,
+    /// File holding the session cookie. Takes precedence over `EVERYBODY_CODES_COOKIE`, which
+    /// takes precedence over the default file in the XDG config directory.
+    #[arg(long, global = true)]
+    pub cookie_file: Option<PathBuf>,
+    /// Time limit in seconds for each part, after which it is reported as timed out
+    #[arg(long, global = true, value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+    /// Never touch the network. Inputs are only decrypted from the cached keys and payloads.
+    #[arg(long, global = true)]
+    pub offline: bool,
+    #[command(flatten)]
+    pub http: HttpConfig,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Accepts absolute URLs, such as `http://localhost:8080/`.
+fn parse_base_url(s: &str) -> Result<String, String> {
+    let uri = Uri::try_from(s).map_err(|err| err.to_string())?;
+    if uri.scheme().is_none() || uri.authority().is_none() {
+        return Err(format!("Expected an absolute URL, got {s:?}"));
+    }
+    Ok(s.to_string())
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds = s.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    Download {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: u16,
+    },
+    Cookie {
+        cookie: String,
+    },
+    /// Check the saved cookie, showing the logged in user and their seed
+    Whoami,
+    Submit {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
+        part: u16,
+        /// Defaults to the answer computed by the solver
+        answer: Option<String>,
+    },
+    /// List the registered quests, and which parts are implemented
+    List,
+    /// Re-run the solutions and compare them against the known answers
+    Verify {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: Option<u16>,
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
+        part: Option<u16>,
+    },
+    /// Download the quest description of the unlocked parts as Markdown, and extract the
+    /// examples into files
+    Describe {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: u16,
+    },
+    /// Re-run a quest whenever its inputs or example files change, showing how the answers
+    /// changed
+    Watch {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
+        part: Option<u16>,
+        /// Seconds between checks for changes
+        #[arg(long, default_value = "1", value_parser = parse_seconds)]
+        interval: Duration,
+    },
+    /// Time the solutions, optionally comparing against a saved baseline
+    Bench(BenchArgs),
+    /// Parse the downloaded inputs and the examples without solving them, reporting those that
+    /// fail
+    Check {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: Option<u16>,
+    },
+    /// Show the solved parts with their accepted answers, next to the answers and timings of the
+    /// solutions. Completion times are known for the answers sent with `submit`.
+    Progress {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: Option<u16>,
+    },
+    /// Generate the module of a new quest, and register it
+    New {
+        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+        day: u16,
+        /// Title shown by the `list` subcommand
+        #[arg(short, long)]
+        title: Option<String>,
+        /// Download the input and the examples of the description first
+        #[arg(long)]
+        download: bool,
+        /// Source directory of the crate to add the module to
+        #[arg(long, default_value = "src")]
+        src_dir: PathBuf,
+    },
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+    pub day: Option<u16>,
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
+    pub part: Option<u16>,
+    /// Untimed runs of each part before sampling
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: u32,
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub samples: u32,
+    /// Save the results as a baseline file
+    #[arg(long)]
+    pub save: Option<PathBuf>,
+    /// Compare the results against a previously saved baseline file
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+    /// Flag parts whose median got slower by more than this percentage
+    #[arg(long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+/// Settings of the HTTP client used to talk to the server.
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub struct HttpConfig {
+    /// Time limit in seconds for each HTTP request
+    #[arg(
+        id = "http_timeout",
+        long = "http-timeout",
+        value_name = "SECONDS",
+        global = true,
+        default_value = "5",
+        value_parser = parse_seconds
+    )]
+    pub timeout: Duration,
+    /// Times to retry a request after a network error, or after the server was too busy
+    #[arg(long, global = true, default_value_t = 3)]
+    pub retries: u32,
+    /// Seconds to wait before the first retry, doubling for each following one. A
+    /// `Retry-After` header from the server takes precedence, but fails the request when it asks
+    /// for longer than the timeout times the retries.
+    #[arg(long, global = true, default_value = "1", value_parser = parse_seconds)]
+    pub backoff: Duration,
+    /// Proxy to send the requests through, such as `http://localhost:8080`
+    #[arg(long, global = true)]
+    pub proxy: Option<String>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            proxy: None,
+        }
+    }
+}
+
+/// Runs the command line, returning the exit code of the process.
+pub fn main(cli: Cli) -> ExitCode {
+    install_panic_hook();
+    if cli.mem {
+        if !cfg!(feature = "mem") {
+            let err = Cli::command().error(
+                ErrorKind::InvalidValue,
+                "--mem needs the counting allocator. Build with `--features mem` to install it.",
+            );
+            let _ = err.print();
+            return ExitCode::from(u8::try_from(err.exit_code()).unwrap_or(2));
+        }
+        memory::enable();
+    }
+    let mut runner = Runner::new(cli.event, &cli.base_url, "./input");
+    runner.set_timeout(cli.timeout);
+    runner.set_cookie_file(cli.cookie_file.clone());
+    runner.set_offline(cli.offline);
+    runner.set_params(cli.params.clone());
+    runner.set_http_config(cli.http.clone());
+    execute(cli, &mut runner).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        ExitCode::from(err.exit_code())
+    })
+}
+
+/// Whether the command uses the solutions, which only exist for the event of the [`REGISTRY`].
+const fn uses_solutions(command: Option<&Command>) -> bool {
+    matches!(
+        command,
+        None | Some(
+            Command::List
+                | Command::Verify { .. }
+                | Command::Bench(_)
+                | Command::Check { .. }
+                | Command::Watch { .. }
+                | Command::Progress { .. }
+                | Command::New { .. }
+        )
+    )
+}
+
+fn execute(cli: Cli, runner: &mut Runner) -> Result<ExitCode, RunnerError> {
+    if uses_solutions(cli.command.as_ref()) && runner.event() != REGISTRY.event {
+        println!("No solutions for event {}", runner.event());
+        return Ok(ExitCode::FAILURE);
+    }
+    if let Some(cmd) = cli.command {
+        match cmd {
+            Command::Cookie { cookie } => runner.save_cookie(&cookie)?,
+            Command::Whoami => {
+                let user = runner.whoami()?;
+                let name = user.name.as_deref().unwrap_or("unknown user");
+                println!("Logged in as {name}, with seed {}", user.seed);
+            }
+            Command::Download { day } => runner.download(day)?,
+            Command::Describe { day } => runner.describe(day)?,
+            Command::Submit { day, part, answer } => {
+                let answer = match answer {
+                    Some(answer) => Some(answer),
+                    None => match REGISTRY.get(day) {
+                        Some(entry) if runner.event() == REGISTRY.event => {
+                            entry.solve(runner, part)?
+                        }
+                        _ => None,
+                    },
+                };
+                let Some(answer) = answer else {
+                    println!("No answer for quest {day} part {part}");
+                    return Ok(ExitCode::FAILURE);
+                };
+                runner.submit(day, part, &answer)?;
+            }
+            Command::List => REGISTRY.print_list(),
+            Command::Verify { day, part } => {
+                let summary = REGISTRY.verify(runner, day, part)?;
+                println!();
+                println!(
+                    "{} passed, {} failed, {} unknown",
+                    summary.passed, summary.failed, summary.unknown
+                );
+                if summary.failed > 0 {
+                    return Ok(ExitCode::from(failed_parts_exit_code(summary.failed)));
+                }
+            }
+            Command::Bench(args) => {
+                return bench(runner, &args);
+            }
+            Command::Check { day } => {
+                let summary = REGISTRY.check(runner, day);
+                println!("{} parsed, {} failed", summary.passed, summary.failed);
+                if summary.failed > 0 {
+                    return Ok(ExitCode::from(failed_parts_exit_code(summary.failed)));
+                }
+            }
+            Command::Watch {
+                day,
+                part,
+                interval,
+            } => REGISTRY.watch(runner, day, part, interval)?,
+            Command::Progress { day } => REGISTRY.print_progress(runner, day)?,
+            Command::New {
+                day,
+                title,
+                download,
+                src_dir,
+            } => return new_day(runner, day, title, download, &src_dir),
+        }
+    } else {
+        if let Some(day) = cli.day
+            && REGISTRY.get(day).is_none()
+        {
+            eprintln!("No solution for quest {day}");
+            return Ok(ExitCode::FAILURE);
+        }
+        let failed = run(&cli, runner)?;
+        if failed > 0 {
+            eprintln!("{failed} parts failed");
+            return Ok(ExitCode::from(failed_parts_exit_code(failed)));
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Exit code of a run with a single failed part, going up by one for each further failed part.
+/// Parts failing `verify` or `check`, and those slower than the `bench` baseline, count the same.
+/// Kept clear of the 1 of other failures and the 2 of usage errors below, and of the 101 of a
+/// panic and the [`RunnerError::exit_code`] above.
+const FAILED_PARTS_EXIT_CODE: u8 = 10;
+
+/// The exit code of a run with `failed` parts, capped below the 101 of a panic.
+fn failed_parts_exit_code(failed: usize) -> u8 {
+    let failed = u8::try_from(failed).unwrap_or(u8::MAX).min(90);
+    FAILED_PARTS_EXIT_CODE - 1 + failed
+}
+
+/// Runs the selected parts, returning the number of failed parts.
+fn run(cli: &Cli, runner: &mut Runner) -> Result<usize, RunnerError> {
+    let sampling = Sampling::repeat(cli.repeat.unwrap_or(1));
+    let mut reporter = Reporter::new(cli.format, cli.mem);
+    let mut failed = 0;
+    if cli.example {
+        let summary = REGISTRY.run_examples(runner, cli.day, cli.part);
+        println!();
+        println!(
+            "{} passed, {} failed, {} known failures, {} skipped",
+            summary.passed, summary.failed, summary.known_failures, summary.skipped
+        );
+        return Ok(summary.failed);
+    }
+    if let Some(input) = &cli.input {
+        // Clap ensures `--input` comes with both `--day` and `--part`.
+        let (Some(entry), Some(part)) = (cli.day.and_then(|day| REGISTRY.get(day)), cli.part)
+        else {
+            return Ok(1);
+        };
+        let input_text = read_input_file(input)?;
+        let report = entry.run_on(runner, part, input_text, sampling);
+        failed += usize::from(report.status.is_failure());
+        reporter.print(&report);
+    } else if cli.parallel {
+        for report in REGISTRY.run_parallel(runner, cli.day, cli.part, sampling)? {
+            failed += usize::from(report.status.is_failure());
+            reporter.print(&report);
+        }
+    } else {
+        for entry in REGISTRY.days {
+            if cli.day.is_some_and(|d| d != entry.day) {
+                continue;
+            }
+            for part in 1..=3 {
+                if cli.part.is_none_or(|p| p == part) {
+                    let report = entry.run(runner, part, sampling)?;
+                    failed += usize::from(report.status.is_failure());
+                    reporter.print(&report);
+                }
+            }
+        }
+    }
+    if cli.format == Format::Text {
+        println!();
+    }
+    Ok(failed)
+}
+
+fn bench(runner: &mut Runner, args: &BenchArgs) -> Result<ExitCode, RunnerError> {
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let sampling = Sampling {
+        warmup: args.warmup,
+        samples: args.samples,
+    };
+    let summary = REGISTRY.bench(
+        runner,
+        args.day,
+        args.part,
+        sampling,
+        baseline.as_ref(),
+        args.threshold,
+    )?;
+    if let Some(save) = &args.save {
+        // Keep the baselines of the parts left out by `--day` and `--part`.
+        let mut saved = if save.exists() {
+            Baseline::load(save)?
+        } else {
+            Baseline::default()
+        };
+        saved.merge(summary.results);
+        saved.save(save)?;
+        eprintln!("Saved {}", save.display());
+    }
+    if summary.regressions > 0 {
+        println!();
+        println!("{} parts got slower", summary.regressions);
+        return Ok(ExitCode::from(failed_parts_exit_code(summary.regressions)));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_day(
+    runner: &mut Runner,
+    day: u16,
+    title: Option<String>,
+    download: bool,
+    src_dir: &Path,
+) -> Result<ExitCode, RunnerError> {
+    if REGISTRY.get(day).is_some() {
+        println!("Quest {day} is already registered");
+        return Ok(ExitCode::FAILURE);
+    }
+    let manifest = src_dir.join("../Cargo.toml");
+    if !manifest.exists() {
+        println!(
+            "{} is not the source directory of a crate, as {} is missing",
+            src_dir.display(),
+            manifest.display()
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+    if download {
+        runner.download(day)?;
+        runner.describe(day)?;
+    }
+    let title = title.unwrap_or_else(|| format!("Quest {day}"));
+    let examples = runner.read_examples(day);
+    if scaffold::create(src_dir, day, &title, &examples)? {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+        assert!(Cli::try_parse_from(["ec", "--example", "-d", "3"]).is_ok());
+        for flag in ["-j", "-r2", "-fjson", "--param=rounds=3"] {
+            assert!(Cli::try_parse_from(["ec", "--example", "-d", "3", flag]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_uses_solutions() {
+        let uses = |args: &[&str]| {
+            let cli = Cli::try_parse_from(args).unwrap();
+            uses_solutions(cli.command.as_ref())
+        };
+        assert!(uses(&["ec", "--event", "2024"]));
+        assert!(uses(&["ec", "check"]));
+        assert!(uses(&["ec", "new", "-d", "21"]));
+        assert!(uses(&["ec", "list", "--event", "2024"]));
+        assert!(!uses(&["ec", "whoami"]));
+        assert!(!uses(&["ec", "download", "-d", "1"]));
+    }
+
+    #[test]
+    fn test_failed_parts_exit_code() {
+        assert_eq!(failed_parts_exit_code(1), 10);
+        assert_eq!(failed_parts_exit_code(2), 11);
+        assert_eq!(failed_parts_exit_code(60), 69);
+        assert_eq!(failed_parts_exit_code(1000), 99);
+    }
+
+    #[test]
+    fn test_parse_base_url() {
+        assert!(parse_base_url(DEFAULT_BASE_URL).is_ok());
+        assert!(parse_base_url("http://127.0.0.1:8080").is_ok());
+        assert!(parse_base_url("not a url").is_err());
+        assert!(parse_base_url("everybody.codes").is_err());
+    }
+}
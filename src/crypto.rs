@@ -0,0 +1,119 @@
+use aes::Aes256;
+use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
+use cbc::Decryptor;
+use serde::{Deserialize, Deserializer};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DecryptError {
+    #[error("Expected a key of 32 bytes, got {0}")]
+    InvalidKeyLength(usize),
+    #[error("Invalid padding, the key is probably wrong")]
+    InvalidPadding,
+    #[error("The decrypted text is not valid UTF-8")]
+    InvalidUtf8,
+}
+
+/// The encrypted parts of an input or a description. Parts not published yet are missing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct InputData {
+    #[serde(rename = "1", default, deserialize_with = "hex_option")]
+    first: Option<Vec<u8>>,
+    #[serde(rename = "2", default, deserialize_with = "hex_option")]
+    second: Option<Vec<u8>>,
+    #[serde(rename = "3", default, deserialize_with = "hex_option")]
+    third: Option<Vec<u8>>,
+}
+
+impl InputData {
+    pub fn part(&self, part: u16) -> Option<&[u8]> {
+        match part {
+            1 => self.first.as_deref(),
+            2 => self.second.as_deref(),
+            3 => self.third.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+fn hex_option<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|hex| hex::decode(hex).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Decrypts a part using AES-256-CBC, with the key of the part. The first 16 bytes of the key
+/// double as the IV.
+pub fn decrypt_part(contents: &[u8], key: &str) -> Result<String, DecryptError> {
+    let key_bytes = key.as_bytes();
+    let cipher = Decryptor::<Aes256>::new_from_slices(key_bytes, &key_bytes[..16.min(key.len())])
+        .map_err(|_| DecryptError::InvalidKeyLength(key_bytes.len()))?;
+    let mut buf = vec![0_u8; contents.len()];
+    let decrypted = cipher
+        .decrypt_padded_b2b_mut::<Pkcs7>(contents, &mut buf)
+        .map_err(|_| DecryptError::InvalidPadding)?;
+    String::from_utf8(decrypted.to_vec()).map_err(|_| DecryptError::InvalidUtf8)
+}
+
+/// Encrypts a part the way the server does, to make fixtures.
+#[cfg(test)]
+pub fn encrypt_part(plain: &[u8], key: &str) -> Vec<u8> {
+    use aes::cipher::BlockEncryptMut;
+
+    let key_bytes = key.as_bytes();
+    let cipher = cbc::Encryptor::<Aes256>::new(key_bytes.into(), key_bytes[..16].into());
+    let mut buf = vec![0_u8; plain.len() + 16];
+    cipher
+        .encrypt_padded_b2b_mut::<Pkcs7>(plain, &mut buf)
+        .unwrap()
+        .to_vec()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    /// A key of the length used by the site, shared by the tests decrypting inputs.
+    pub const KEY: &str = "0123456789abcdefghijklmnopqrstuv";
+
+    #[test_case("" ; "empty")]
+    #[test_case("10,5,1,10,3,8,5,2,2" ; "single block")]
+    #[test_case("Vyrdax,Drakzyph,Fyrryn,Elarzris\n\nR3,L2,R3,L1" ; "several blocks")]
+    fn test_roundtrip(plain: &str) {
+        let encrypted = encrypt_part(plain.as_bytes(), KEY);
+        assert_eq!(encrypted.len() % 16, 0);
+        assert_eq!(decrypt_part(&encrypted, KEY).as_deref(), Ok(plain));
+    }
+
+    #[test]
+    fn test_errors() {
+        let encrypted = encrypt_part(b"10,5,1", KEY);
+        assert_eq!(
+            decrypt_part(&encrypted, "too short"),
+            Err(DecryptError::InvalidKeyLength(9))
+        );
+        assert_eq!(
+            decrypt_part(&encrypted, "ABCDEFGHIJKLMNOPQRSTUVWXYZ012345"),
+            Err(DecryptError::InvalidPadding)
+        );
+        assert_eq!(
+            decrypt_part(&encrypted[..8], KEY),
+            Err(DecryptError::InvalidPadding)
+        );
+        let encrypted = encrypt_part(&[0xff, 0xfe], KEY);
+        assert_eq!(
+            decrypt_part(&encrypted, KEY),
+            Err(DecryptError::InvalidUtf8)
+        );
+    }
+
+    #[test]
+    fn test_input_data() {
+        let input = serde_json::from_str::<InputData>(r#"{"1":"0a0b","3":null}"#).unwrap();
+        assert_eq!(input.part(1), Some([10, 11].as_slice()));
+        assert_eq!(input.part(2), None);
+        assert_eq!(input.part(3), None);
+        assert!(serde_json::from_str::<InputData>(r#"{"1":"xyz"}"#).is_err());
+    }
+}
@@ -4,6 +4,7 @@ use std::str::FromStr;
 use thiserror::Error;
 
 use crate::Day;
+use crate::registry::Example;
 
 #[derive(Debug, Error)]
 pub enum ParseError {
@@ -14,7 +15,7 @@ pub enum ParseError {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     Left(usize),
     Right(usize),
 }
@@ -37,6 +38,16 @@ pub struct Input {
     instructions: Vec<Instruction>,
 }
 
+impl Input {
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
 impl FromStr for Input {
     type Err = ParseError;
 
@@ -69,6 +80,8 @@ pub struct Day01;
 impl Day for Day01 {
     type Input = Input;
     type ParseError = ParseError;
+    type Params = ();
+    const PARTS: &[u16] = &[1, 2, 3];
 
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
@@ -110,21 +123,28 @@ impl Day for Day01 {
     }
 }
 
+const EXAMPLE1: &str = "\
+    Vyrdax,Drakzyph,Fyrryn,Elarzris\n\
+    \n\
+    R3,L2,R3,L1\
+";
+
+const EXAMPLE2: &str = "\
+    Vyrdax,Drakzyph,Fyrryn,Elarzris\n\
+    \n\
+    R3,L2,R3,L3\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "Fyrryn"),
+    Example::new(2, EXAMPLE1, "Elarzris"),
+    Example::new(3, EXAMPLE2, "Drakzyph"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    const EXAMPLE1: &str = "\
-        Vyrdax,Drakzyph,Fyrryn,Elarzris\n\
-        \n\
-        R3,L2,R3,L1\
-    ";
-
-    const EXAMPLE2: &str = "\
-        Vyrdax,Drakzyph,Fyrryn,Elarzris\n\
-        \n\
-        R3,L2,R3,L3\
-    ";
+    use crate::registry::check_examples;
 
     #[test]
     fn test_parse() {
@@ -142,23 +162,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let input = EXAMPLE1.parse().unwrap();
-        let result = Day01::part_1(&input);
-        assert_eq!(result, "Fyrryn");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = EXAMPLE1.parse().unwrap();
-        let result = Day01::part_2(&input);
-        assert_eq!(result, "Elarzris");
-    }
-
-    #[test]
-    fn test_part_3() {
-        let input = EXAMPLE2.parse().unwrap();
-        let result = Day01::part_3(&input);
-        assert_eq!(result, "Drakzyph");
+    fn test_examples() {
+        check_examples::<Day01>(EXAMPLES);
     }
 }
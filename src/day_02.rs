@@ -7,6 +7,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use thiserror::Error;
 
 use crate::Day;
+use crate::registry::Example;
 
 #[derive(Debug, Error)]
 pub enum ParseError {
@@ -27,6 +28,14 @@ impl Complex {
         Self { x, y }
     }
 
+    pub const fn x(self) -> i64 {
+        self.x
+    }
+
+    pub const fn y(self) -> i64 {
+        self.y
+    }
+
     pub const fn exceeds(self, limit: u64) -> bool {
         self.x.unsigned_abs() > limit || self.y.unsigned_abs() > limit
     }
@@ -111,6 +120,8 @@ pub struct Day02;
 impl Day for Day02 {
     type Input = Complex;
     type ParseError = ParseError;
+    type Params = ();
+    const PARTS: &[u16] = &[1, 2, 3];
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input
             .strip_prefix("A=")
@@ -168,14 +179,22 @@ impl Day for Day02 {
     }
 }
 
+const EXAMPLE1: &str = "A=[25,9]";
+
+const EXAMPLE2: &str = "A=[35300,-64910]";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "[357,862]"),
+    Example::new(2, EXAMPLE2, "4076"),
+    Example::new(3, EXAMPLE2, "406954"),
+];
+
 #[cfg(test)]
 mod tests {
     use image::{ImageBuffer, Rgb};
 
     use super::*;
-
-    const EXAMPLE1: &str = "A=[25,9]";
-    const EXAMPLE2: &str = "A=[35300,-64910]";
+    use crate::registry::check_examples;
 
     #[test]
     fn test_parse() {
@@ -183,27 +202,6 @@ mod tests {
         assert_eq!(result, Complex::new(25, 9));
     }
 
-    #[test]
-    fn test_part_1() {
-        let input = Day02::parse(EXAMPLE1).unwrap();
-        let result = Day02::part_1(&input);
-        assert_eq!(result, Complex::new(357, 862));
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = Day02::parse(EXAMPLE2).unwrap();
-        let result = Day02::part_2(&input);
-        assert_eq!(result, 4_076);
-    }
-
-    #[test]
-    fn test_part_3() {
-        let input = Day02::parse(EXAMPLE2).unwrap();
-        let result = Day02::part_3(&input);
-        assert_eq!(result, 406_954);
-    }
-
     #[test]
     #[ignore = "Generates image"]
     fn test_render() {
@@ -242,4 +240,9 @@ mod tests {
             .unwrap();
         println!("Saved image to {filename}");
     }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day02>(EXAMPLES);
+    }
 }
@@ -1,12 +1,15 @@
 use std::num::ParseIntError;
 
 use crate::Day;
+use crate::registry::Example;
 
 pub struct Day03;
 
 impl Day for Day03 {
     type Input = Vec<u16>;
     type ParseError = ParseIntError;
+    type Params = ();
+    const PARTS: &[u16] = &[1, 2, 3];
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         let mut result = input
             .split(',')
@@ -36,40 +39,29 @@ impl Day for Day03 {
         smallest_set
     }
 
-    fn part_3(input: &Self::Input) -> usize{
-        input
-            .chunk_by(PartialEq::eq)
-            .map(<[_]>::len)
-            .max()
-            .unwrap()
+    fn part_3(input: &Self::Input) -> usize {
+        input.chunk_by(PartialEq::eq).map(<[_]>::len).max().unwrap()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLE1: &str = "10,5,1,10,3,8,5,2,2";
 
-    const EXAMPLE1: &str = "10,5,1,10,3,8,5,2,2";
-    const EXAMPLE2: &str = "4,51,13,64,57,51,82,57,16,88,89,48,32,49,49,2,84,65,49,43,9,13,2,3,75,72,63,48,61,14,40,77";
+const EXAMPLE2: &str =
+    "4,51,13,64,57,51,82,57,16,88,89,48,32,49,49,2,84,65,49,43,9,13,2,3,75,72,63,48,61,14,40,77";
 
-    #[test]
-    fn test_part_1() {
-        let input = Day03::parse(EXAMPLE1).unwrap();
-        let result = Day03::part_1(&input);
-        assert_eq!(result, 29);
-    }
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "29"),
+    Example::new(2, EXAMPLE2, "781"),
+    Example::new(3, EXAMPLE2, "3"),
+];
 
-    #[test]
-    fn test_part_2() {
-        let input = Day03::parse(EXAMPLE2).unwrap();
-        let result = Day03::part_2(&input);
-        assert_eq!(result, 781);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_3() {
-        let input = Day03::parse(EXAMPLE2).unwrap();
-        let result = Day03::part_3(&input);
-        assert_eq!(result, 3);
+    fn test_examples() {
+        check_examples::<Day03>(EXAMPLES);
     }
 }
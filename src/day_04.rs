@@ -1,6 +1,8 @@
 use std::num::ParseIntError;
 use std::str::FromStr;
 
+use crate::registry::Example;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Gear {
     Single(u64),
@@ -26,6 +28,10 @@ impl crate::Day for Day04 {
 
     type ParseError = ParseIntError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.lines().map(str::parse).collect()
     }
@@ -59,64 +65,55 @@ impl crate::Day for Day04 {
     }
 }
 
+const EXAMPLE1: &str = "\
+    128\n\
+    64\n\
+    32\n\
+    16\n\
+    8\
+";
+
+const EXAMPLE2: &str = "\
+    102\n\
+    75\n\
+    50\n\
+    35\n\
+    13\
+";
+
+const EXAMPLE3: &str = "\
+    5\n\
+    5|10\n\
+    10|20\n\
+    5\
+";
+
+const EXAMPLE4: &str = "\
+    5\n\
+    7|21\n\
+    18|36\n\
+    27|27\n\
+    10|50\n\
+    10|50\n\
+    11\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "32400"),
+    Example::new(1, EXAMPLE2, "15888"),
+    Example::new(2, EXAMPLE1, "625000000000"),
+    Example::new(2, EXAMPLE2, "1274509803922"),
+    Example::new(3, EXAMPLE3, "400"),
+    Example::new(3, EXAMPLE4, "6818"),
+];
+
 #[cfg(test)]
 mod tests {
-    use crate::Day;
-
     use super::*;
-    use test_case::test_case;
-
-    const EXAMPLE1: &str = "\
-        128\n\
-        64\n\
-        32\n\
-        16\n\
-        8\
-    ";
-
-    const EXAMPLE2: &str = "\
-        102\n\
-        75\n\
-        50\n\
-        35\n\
-        13\
-    ";
-
-    const EXAMPLE3: &str = "\
-        5\n\
-        5|10\n\
-        10|20\n\
-        5\
-    ";
-
-    const EXAMPLE4: &str = "\
-        5\n\
-        7|21\n\
-        18|36\n\
-        27|27\n\
-        10|50\n\
-        10|50\n\
-        11\
-    ";
-
-    #[test_case(EXAMPLE1 => 32_400)]
-    #[test_case(EXAMPLE2 => 15_888)]
-    fn test_part_1(input: &str) -> u64 {
-        let gears = Day04::parse(input).unwrap();
-        Day04::part_1(&gears)
-    }
-
-    #[test_case(EXAMPLE1 => 625_000_000_000)]
-    #[test_case(EXAMPLE2 => 1_274_509_803_922)]
-    fn test_part_2(input: &str) -> u64 {
-        let gears = Day04::parse(input).unwrap();
-        Day04::part_2(&gears)
-    }
+    use crate::registry::check_examples;
 
-    #[test_case(EXAMPLE3 => 400)]
-    #[test_case(EXAMPLE4 => 6_818)]
-    fn test_part_3(input: &str) -> u64 {
-        let gears = Day04::parse(input).unwrap();
-        Day04::part_3(&gears)
+    #[test]
+    fn test_examples() {
+        check_examples::<Day04>(EXAMPLES);
     }
 }
@@ -5,6 +5,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -20,6 +22,15 @@ pub struct Sword {
 }
 
 impl Sword {
+    pub const fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// The segments of the spine, each with the numbers to its left and right
+    pub fn fishbone(&self) -> &[(Option<u8>, u8, Option<u8>)] {
+        &self.fishbone
+    }
+
     const fn len(&self) -> usize {
         self.fishbone.len()
     }
@@ -112,6 +123,10 @@ impl crate::Day for Day05 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.lines().map(str::parse).collect()
     }
@@ -143,61 +158,52 @@ impl crate::Day for Day05 {
     }
 }
 
+const EXAMPLE1: &str = "58:5,3,7,8,9,10,4,5,7,8,8";
+
+const EXAMPLE2: &str = "\
+    1:2,4,1,1,8,2,7,9,8,6\n\
+    2:7,9,9,3,8,3,8,8,6,8\n\
+    3:4,7,6,9,1,8,3,7,2,2\n\
+    4:6,4,2,1,7,4,5,5,5,8\n\
+    5:2,9,3,8,3,9,5,2,1,4\n\
+    6:2,4,9,6,7,4,1,7,6,8\n\
+    7:2,3,7,6,2,2,4,1,4,2\n\
+    8:5,1,5,6,8,3,1,8,3,9\n\
+    9:5,7,7,3,7,2,3,8,6,7\n\
+    10:4,1,9,3,8,5,4,3,5,5\
+";
+
+const EXAMPLE3: &str = "\
+    1:7,1,9,1,6,9,8,3,7,2\n\
+    2:6,1,9,2,9,8,8,4,3,1\n\
+    3:7,1,9,1,6,9,8,3,8,3\n\
+    4:6,1,9,2,8,8,8,4,3,1\n\
+    5:7,1,9,1,6,9,8,3,7,3\n\
+    6:6,1,9,2,8,8,8,4,3,5\n\
+    7:3,7,2,2,7,4,4,6,3,1\n\
+    8:3,7,2,2,7,4,4,6,3,7\n\
+    9:3,7,2,2,7,4,1,6,3,7\
+";
+
+const EXAMPLE4: &str = "\
+    1:7,1,9,1,6,9,8,3,7,2\n\
+    2:7,1,9,1,6,9,8,3,7,2\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "581078"),
+    Example::new(2, EXAMPLE2, "77053"),
+    Example::new(3, EXAMPLE3, "260"),
+    Example::new(3, EXAMPLE4, "4"),
+];
+
 #[cfg(test)]
 mod tests {
-    use crate::Day;
-
     use super::*;
-    use test_case::test_case;
-
-    const EXAMPLE1: &str = "58:5,3,7,8,9,10,4,5,7,8,8";
-
-    const EXAMPLE2: &str = "\
-        1:2,4,1,1,8,2,7,9,8,6\n\
-        2:7,9,9,3,8,3,8,8,6,8\n\
-        3:4,7,6,9,1,8,3,7,2,2\n\
-        4:6,4,2,1,7,4,5,5,5,8\n\
-        5:2,9,3,8,3,9,5,2,1,4\n\
-        6:2,4,9,6,7,4,1,7,6,8\n\
-        7:2,3,7,6,2,2,4,1,4,2\n\
-        8:5,1,5,6,8,3,1,8,3,9\n\
-        9:5,7,7,3,7,2,3,8,6,7\n\
-        10:4,1,9,3,8,5,4,3,5,5\
-    ";
-
-    const EXAMPLE3: &str = "\
-        1:7,1,9,1,6,9,8,3,7,2\n\
-        2:6,1,9,2,9,8,8,4,3,1\n\
-        3:7,1,9,1,6,9,8,3,8,3\n\
-        4:6,1,9,2,8,8,8,4,3,1\n\
-        5:7,1,9,1,6,9,8,3,7,3\n\
-        6:6,1,9,2,8,8,8,4,3,5\n\
-        7:3,7,2,2,7,4,4,6,3,1\n\
-        8:3,7,2,2,7,4,4,6,3,7\n\
-        9:3,7,2,2,7,4,1,6,3,7\
-    ";
-
-    const EXAMPLE4: &str = "\
-        1:7,1,9,1,6,9,8,3,7,2\n\
-        2:7,1,9,1,6,9,8,3,7,2\
-    ";
-
-    #[test_case(EXAMPLE1 => 581_078)]
-    fn test_part_1(input: &str) -> u64 {
-        let input = Day05::parse(input).unwrap();
-        Day05::part_1(&input)
-    }
-
-    #[test_case(EXAMPLE2 => 77_053)]
-    fn test_part_2(input: &str) -> u64 {
-        let input = Day05::parse(input).unwrap();
-        Day05::part_2(&input)
-    }
+    use crate::registry::check_examples;
 
-    #[test_case(EXAMPLE3 => 260)]
-    #[test_case(EXAMPLE4 => 4)]
-    fn test_part_3(input: &str) -> u64 {
-        let input = Day05::parse(input).unwrap();
-        Day05::part_3(&input)
+    #[test]
+    fn test_examples() {
+        check_examples::<Day05>(EXAMPLES);
     }
 }
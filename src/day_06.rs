@@ -1,12 +1,46 @@
 use std::num::ParseIntError;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 pub struct Day06;
 
+/// How far apart a squire and a mentor may sit, and how many times the pattern repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub distance: usize,
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            distance: 1000,
+            cycles: 1000,
+        }
+    }
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "distance" => self.distance = parse_value(name, value)?,
+            "cycles" => self.cycles = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day06 {
     type Input = String;
 
     type ParseError = ParseIntError;
 
+    type Params = Params;
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         Ok(input.to_string())
     }
@@ -22,7 +56,12 @@ impl crate::Day for Day06 {
     }
 
     fn part_3(input: &Self::Input) -> usize {
-        number_of_pairings_shortcut(input, 1000, 1000, 1000)
+        Self::part_3_with(input, &Params::default())
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Params) -> usize {
+        let Params { distance, cycles } = *params;
+        number_of_pairings_shortcut(input, cycles, distance, distance)
             .into_iter()
             .sum()
     }
@@ -72,29 +111,25 @@ fn number_of_pairings(input: &str, cycles: usize, behind: usize, ahead: usize) -
     pairs
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Day;
+const EXAMPLE1: &str = "ABabACacBCbca";
 
-    use super::*;
-    use test_case::test_case;
+const EXAMPLE2: &str = "AABCBABCABCabcabcABCCBAACBCa";
 
-    #[test_case("ABabACacBCbca" => 5)]
-    fn test_part_1(input: &str) -> usize {
-        Day06::part_1(&input.to_string())
-    }
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "5"),
+    Example::new(2, EXAMPLE1, "11"),
+    Example::new(3, EXAMPLE2, "34").with_params(&[("distance", "10"), ("cycles", "1")]),
+    Example::new(3, EXAMPLE2, "72").with_params(&[("distance", "10"), ("cycles", "2")]),
+    Example::new(3, EXAMPLE2, "3442321"),
+];
 
-    #[test_case("ABabACacBCbca" => 11)]
-    fn test_part_2(input: &str) -> usize {
-        Day06::part_2(&input.to_string())
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
 
-    #[test_case("AABCBABCABCabcabcABCCBAACBCa", 10, 1 => 34)]
-    #[test_case("AABCBABCABCabcabcABCCBAACBCa", 10, 2 => 72)]
-    #[test_case("AABCBABCABCabcabcABCCBAACBCa", 1_000, 1_000 => 3_442_321)]
-    fn test_part_3(input: &str, dist_limit: usize, cycles: usize) -> usize {
-        number_of_pairings_shortcut(input, cycles, dist_limit, dist_limit)
-            .into_iter()
-            .sum()
+    #[test]
+    fn test_examples() {
+        check_examples::<Day06>(EXAMPLES);
     }
 }
@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -61,7 +63,8 @@ impl Debug for RuleSet {
 }
 
 impl RuleSet {
-    const fn is_valid(&self, before: u8, after: u8) -> bool {
+    /// Whether the letter `after` may follow the letter `before`
+    pub const fn is_valid(&self, before: u8, after: u8) -> bool {
         (self.rules[(before - b'A') as usize] & (1 << (after - b'A'))) != 0
     }
 
@@ -109,6 +112,16 @@ pub struct Input {
     rules: RuleSet,
 }
 
+impl Input {
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub const fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+}
+
 impl FromStr for Input {
     type Err = ParseError;
 
@@ -135,6 +148,10 @@ impl crate::Day for Day07 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
@@ -227,107 +244,97 @@ fn count_possible_continuations(
     sum
 }
 
+const EXAMPLE1: &str = "\
+    Oronris,Urakris,Oroneth,Uraketh\n\
+    \n\
+    r > a,i,o\n\
+    i > p,w\n\
+    n > e,r\n\
+    o > n,m\n\
+    k > f,r\n\
+    a > k\n\
+    U > r\n\
+    e > t\n\
+    O > r\n\
+    t > h\
+";
+
+const EXAMPLE2: &str = "\
+    Xanverax,Khargyth,Nexzeth,Helther,Braerex,Tirgryph,Kharverax\n\
+    \n\
+    r > v,e,a,g,y\n\
+    a > e,v,x,r\n\
+    e > r,x,v,t\n\
+    h > a,e,v\n\
+    g > r,y\n\
+    y > p,t\n\
+    i > v,r\n\
+    K > h\n\
+    v > e\n\
+    B > r\n\
+    t > h\n\
+    N > e\n\
+    p > h\n\
+    H > e\n\
+    l > t\n\
+    z > e\n\
+    X > a\n\
+    n > v\n\
+    x > z\n\
+    T > i\n\
+";
+
+const EXAMPLE3: &str = "\
+    Xaryt\n\
+    \n\
+    X > a,o\n\
+    a > r,t\n\
+    r > y,e,a\n\
+    h > a,e,v\n\
+    t > h\n\
+    v > e\n\
+    y > p,t\
+";
+
+const EXAMPLE4: &str = "\
+    Khara,Xaryt,Noxer,Kharax\n\
+    \n\
+    r > v,e,a,g,y\n\
+    a > e,v,x,r,g\n\
+    e > r,x,v,t\n\
+    h > a,e,v\n\
+    g > r,y\n\
+    y > p,t\n\
+    i > v,r\n\
+    K > h\n\
+    v > e\n\
+    B > r\n\
+    t > h\n\
+    N > e\n\
+    p > h\n\
+    H > e\n\
+    l > t\n\
+    z > e\n\
+    X > a\n\
+    n > v\n\
+    x > z\n\
+    T > i\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "Oroneth"),
+    Example::new(2, EXAMPLE2, "23"),
+    Example::new(3, EXAMPLE3, "25"),
+    Example::new(3, EXAMPLE4, "1154"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-
-    use test_case::test_case;
-
-    const EXAMPLE1: &str = "\
-        Oronris,Urakris,Oroneth,Uraketh\n\
-        \n\
-        r > a,i,o\n\
-        i > p,w\n\
-        n > e,r\n\
-        o > n,m\n\
-        k > f,r\n\
-        a > k\n\
-        U > r\n\
-        e > t\n\
-        O > r\n\
-        t > h\
-    ";
-    const EXAMPLE2: &str = "\
-        Xanverax,Khargyth,Nexzeth,Helther,Braerex,Tirgryph,Kharverax\n\
-        \n\
-        r > v,e,a,g,y\n\
-        a > e,v,x,r\n\
-        e > r,x,v,t\n\
-        h > a,e,v\n\
-        g > r,y\n\
-        y > p,t\n\
-        i > v,r\n\
-        K > h\n\
-        v > e\n\
-        B > r\n\
-        t > h\n\
-        N > e\n\
-        p > h\n\
-        H > e\n\
-        l > t\n\
-        z > e\n\
-        X > a\n\
-        n > v\n\
-        x > z\n\
-        T > i\n\
-    ";
-
-    const EXAMPLE3: &str = "\
-        Xaryt\n\
-        \n\
-        X > a,o\n\
-        a > r,t\n\
-        r > y,e,a\n\
-        h > a,e,v\n\
-        t > h\n\
-        v > e\n\
-        y > p,t\
-    ";
-
-    const EXAMPLE4: &str = "\
-        Khara,Xaryt,Noxer,Kharax\n\
-        \n\
-        r > v,e,a,g,y\n\
-        a > e,v,x,r,g\n\
-        e > r,x,v,t\n\
-        h > a,e,v\n\
-        g > r,y\n\
-        y > p,t\n\
-        i > v,r\n\
-        K > h\n\
-        v > e\n\
-        B > r\n\
-        t > h\n\
-        N > e\n\
-        p > h\n\
-        H > e\n\
-        l > t\n\
-        z > e\n\
-        X > a\n\
-        n > v\n\
-        x > z\n\
-        T > i\
-    ";
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_1() {
-        let input = Day07::parse(EXAMPLE1).unwrap();
-        let result = Day07::part_1(&input);
-        assert_eq!(result, "Oroneth");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = Day07::parse(EXAMPLE2).unwrap();
-        let result = Day07::part_2(&input);
-        assert_eq!(result, 23);
-    }
-
-    #[test_case(EXAMPLE3 => 25)]
-    #[test_case(EXAMPLE4 => 1154)]
-    fn test_part_3(input: &str) -> usize {
-        let input = Day07::parse(input).unwrap();
-        Day07::part_3(&input)
+    fn test_examples() {
+        check_examples::<Day07>(EXAMPLES);
     }
 }
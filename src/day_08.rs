@@ -1,12 +1,35 @@
 use std::num::ParseIntError;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 pub struct Day08;
 
+/// The number of nails around the circle, by default 32 in part 1 and 256 in parts 2 and 3.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Params {
+    pub nails: Option<u16>,
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "nails" => self.nails = Some(parse_value(name, value)?),
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day08 {
     type Input = Vec<(u16, u16)>;
 
     type ParseError = ParseIntError;
 
+    type Params = Params;
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         let sequence: Vec<u16> = input.split(',').map(str::parse).collect::<Result<_, _>>()?;
         let mut sequence: Vec<(u16, u16)> = sequence
@@ -19,15 +42,27 @@ impl crate::Day for Day08 {
     }
 
     fn part_1(input: &Self::Input) -> u64 {
-        center_crossings(input, 32)
+        Self::part_1_with(input, &Params::default())
     }
 
     fn part_2(input: &Self::Input) -> usize {
-        all_crossings(input, 256)
+        Self::part_2_with(input, &Params::default())
     }
 
     fn part_3(input: &Self::Input) -> i32 {
-        best_cut(input, 256)
+        Self::part_3_with(input, &Params::default())
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> u64 {
+        center_crossings(input, params.nails.unwrap_or(32))
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> usize {
+        all_crossings(input, params.nails.map_or(256, usize::from))
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Params) -> i32 {
+        best_cut(input, params.nails.unwrap_or(256))
     }
 }
 
@@ -80,34 +115,25 @@ fn best_cut(sequence: &[(u16, u16)], nails: u16) -> i32 {
     count
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::Day;
+const EXAMPLE1: &str = "1,5,2,6,8,4,1,7,3";
 
-    use super::*;
+const EXAMPLE2: &str = "1,5,2,6,8,4,1,7,3,5,7,8,2";
 
-    const EXAMPLE1: &str = "1,5,2,6,8,4,1,7,3";
-    const EXAMPLE2: &str = "1,5,2,6,8,4,1,7,3,5,7,8,2";
-    const EXAMPLE3: &str = "1,5,2,6,8,4,1,7,3,6";
+const EXAMPLE3: &str = "1,5,2,6,8,4,1,7,3,6";
 
-    #[test]
-    fn test_part_1() {
-        let input = Day08::parse(EXAMPLE1).unwrap();
-        let result = center_crossings(&input, 8);
-        assert_eq!(result, 4);
-    }
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "4").with_params(&[("nails", "8")]),
+    Example::new(2, EXAMPLE2, "21").with_params(&[("nails", "8")]),
+    Example::new(3, EXAMPLE3, "7").with_params(&[("nails", "8")]),
+];
 
-    #[test]
-    fn test_part_2() {
-        let input = Day08::parse(EXAMPLE2).unwrap();
-        let result = all_crossings(&input, 8);
-        assert_eq!(result, 21);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_3() {
-        let input = Day08::parse(EXAMPLE3).unwrap();
-        let result = best_cut(&input, 8);
-        assert_eq!(result, 7);
+    fn test_examples() {
+        check_examples::<Day08>(EXAMPLES);
     }
 }
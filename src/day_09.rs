@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -48,6 +50,23 @@ pub struct ScaleDNA {
 }
 
 impl ScaleDNA {
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The nucleobases of the DNA, in order
+    pub fn sequence(&self) -> impl Iterator<Item = Nucleobase> + '_ {
+        (0..128).map_while(
+            |ix: usize| match (self.mask[ix >> 5] >> ((ix & 0x1f) << 2)) & 0xf {
+                1 => Some(Nucleobase::C),
+                2 => Some(Nucleobase::G),
+                4 => Some(Nucleobase::A),
+                8 => Some(Nucleobase::T),
+                _ => None,
+            },
+        )
+    }
+
     fn except(&self, other: &Self) -> Self {
         Self {
             id: other.id,
@@ -164,6 +183,10 @@ impl crate::Day for Day09 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.lines().map(str::parse).collect()
     }
@@ -241,55 +264,58 @@ impl crate::Day for Day09 {
     }
 }
 
+const EXAMPLE1: &str = "\
+    1:CAAGCGCTAAGTTCGCTGGATGTGTGCCCGCG\n\
+    2:CTTGAATTGGGCCGTTTACCTGGTTTAACCAT\n\
+    3:CTAGCGCTGAGCTGGCTGCCTGGTTGACCGCG\
+";
+
+const EXAMPLE2: &str = "\
+    1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC\n\
+    2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC\n\
+    3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG\n\
+    4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA\n\
+    5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA\n\
+    6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA\n\
+    7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG\
+";
+
+const EXAMPLE3: &str = "\
+    1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC\n\
+    2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC\n\
+    3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG\n\
+    4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA\n\
+    5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA\n\
+    6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA\n\
+    7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG\n\
+    8:GGCGTAAAGTATGGATGCTGGCTAGGCACCCG\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "414"),
+    Example::new(2, EXAMPLE2, "1245"),
+    Example::new(3, EXAMPLE2, "12")
+        .known_failure("picks the 8 most similar scales, but the example only has 7"),
+    Example::new(3, EXAMPLE3, "36"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-    use test_case::test_case;
-
-    const EXAMPLE1: &str = "\
-        1:CAAGCGCTAAGTTCGCTGGATGTGTGCCCGCG\n\
-        2:CTTGAATTGGGCCGTTTACCTGGTTTAACCAT\n\
-        3:CTAGCGCTGAGCTGGCTGCCTGGTTGACCGCG\
-    ";
-
-    const EXAMPLE2: &str = "\
-        1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC\n\
-        2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC\n\
-        3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG\n\
-        4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA\n\
-        5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA\n\
-        6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA\n\
-        7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG\
-    ";
-
-    const EXAMPLE3: &str = "\
-        1:GCAGGCGAGTATGATACCCGGCTAGCCACCCC\n\
-        2:TCTCGCGAGGATATTACTGGGCCAGACCCCCC\n\
-        3:GGTGGAACATTCGAAAGTTGCATAGGGTGGTG\n\
-        4:GCTCGCGAGTATATTACCGAACCAGCCCCTCA\n\
-        5:GCAGCTTAGTATGACCGCCAAATCGCGACTCA\n\
-        6:AGTGGAACCTTGGATAGTCTCATATAGCGGCA\n\
-        7:GGCGTAATAATCGGATGCTGCAGAGGCTGCTG\n\
-        8:GGCGTAAAGTATGGATGCTGGCTAGGCACCCG\
-    ";
-
-    #[test_case(EXAMPLE1 => 414)]
-    fn test_part_1(input: &str) -> u32 {
-        let scales = Day09::parse(input).unwrap();
-        Day09::part_1(&scales)
-    }
+    use crate::registry::check_examples;
 
-    #[test_case(EXAMPLE2 => 1245)]
-    fn test_part_2(input: &str) -> u32 {
-        let scales = Day09::parse(input).unwrap();
-        Day09::part_2(&scales)
+    #[test]
+    fn test_examples() {
+        check_examples::<Day09>(EXAMPLES);
     }
 
-    #[test_case(EXAMPLE2 => 12)]
-    #[test_case(EXAMPLE3 => 36)]
-    fn test_part_3(input: &str) -> usize {
-        let scales = Day09::parse(input).unwrap();
-        Day09::part_3(&scales)
+    #[test]
+    fn test_sequence() {
+        let scale = "7:GATC".parse::<ScaleDNA>().unwrap();
+        assert_eq!(scale.id(), 7);
+        assert_eq!(
+            scale.sequence().collect::<Vec<_>>(),
+            [Nucleobase::G, Nucleobase::A, Nucleobase::T, Nucleobase::C]
+        );
     }
 }
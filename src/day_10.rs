@@ -4,26 +4,38 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
     SyntaxError,
 }
 
+/// A square of the board, shown in chess notation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
+pub struct Pos {
     row: u8,
     col: u8,
 }
 
 impl Pos {
-    fn new(row: usize, col: usize) -> Self {
+    pub fn new(row: usize, col: usize) -> Self {
         Self {
             row: u8::try_from(row).unwrap(),
             col: u8::try_from(col).unwrap(),
         }
     }
 
+    pub const fn row(self) -> usize {
+        self.row as usize
+    }
+
+    pub const fn col(self) -> usize {
+        self.col as usize
+    }
+
     fn sub_row(self, rows: usize) -> Option<Self> {
         self.row
             .checked_sub(u8::try_from(rows).unwrap())
@@ -99,11 +111,24 @@ pub struct Board {
 }
 
 impl Board {
-    fn has_sheep_at(&self, pos: Pos) -> bool {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn dragon(&self) -> Pos {
+        self.dragon
+    }
+
+    pub fn has_sheep_at(&self, pos: Pos) -> bool {
         self.sheep[pos.into_index(self.width)]
     }
 
-    fn is_blocked(&self, pos: Pos) -> bool {
+    /// Whether the square is a hideout, where the sheep can not be eaten
+    pub fn is_blocked(&self, pos: Pos) -> bool {
         self.blocked[pos.into_index(self.width)]
     }
 
@@ -527,127 +552,156 @@ impl Display for Game<'_> {
 
 pub struct Day10;
 
+/// How many moves the dragon makes in part 1, and how many rounds are played in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub moves: usize,
+    pub rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            moves: 4,
+            rounds: 20,
+        }
+    }
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "moves" => self.moves = parse_value(name, value)?,
+            "rounds" => self.rounds = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day10 {
     type Input = Board;
 
     type ParseError = ParseError;
 
+    type Params = Params;
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
 
     fn part_1(input: &Self::Input) -> usize {
-        StaticSheep::new(input).reachable_static_sheep(4)
+        Self::part_1_with(input, &Params::default())
     }
 
     fn part_2(input: &Self::Input) -> usize {
-        DynamicSheep::new(input).reachable_moving_sheep(20)
+        Self::part_2_with(input, &Params::default())
     }
 
     fn part_3(input: &Self::Input) -> usize {
         Game::new(input).count_winning_games()
     }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> usize {
+        StaticSheep::new(input).reachable_static_sheep(params.moves)
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> usize {
+        DynamicSheep::new(input).reachable_moving_sheep(params.rounds)
+    }
 }
 
+const P1_EXAMPLE: &str = "\
+    ...SSS.......\n\
+    .S......S.SS.\n\
+    ..S....S...S.\n\
+    ..........SS.\n\
+    ..SSSS...S...\n\
+    .....SS..S..S\n\
+    SS....D.S....\n\
+    S.S..S..S....\n\
+    ....S.......S\n\
+    .SSS..SS.....\n\
+    .........S...\n\
+    .......S....S\n\
+    SS.....S..S..\
+";
+
+const P2_EXAMPLE: &str = "\
+    ...SSS##.....\n\
+    .S#.##..S#SS.\n\
+    ..S.##.S#..S.\n\
+    .#..#S##..SS.\n\
+    ..SSSS.#.S.#.\n\
+    .##..SS.#S.#S\n\
+    SS##.#D.S.#..\n\
+    S.S..S..S###.\n\
+    .##.S#.#....S\n\
+    .SSS.#SS..##.\n\
+    ..#.##...S##.\n\
+    .#...#.S#...S\n\
+    SS...#.S.#S..\
+";
+
+const P3_EXAMPLE1: &str = "\
+    SSS\n\
+    ..#\n\
+    #.#\n\
+    #D.\
+";
+
+const P3_EXAMPLE2: &str = "\
+    SSS\n\
+    ..#\n\
+    ..#\n\
+    .##\n\
+    .D#\
+";
+
+const P3_EXAMPLE3: &str = "\
+    ..S..\n\
+    .....\n\
+    ..#..\n\
+    .....\n\
+    ..D..\
+";
+
+const P3_EXAMPLE4: &str = "\
+    .SS.S\n\
+    #...#\n\
+    ...#.\n\
+    ##..#\n\
+    .####\n\
+    ##D.#\
+";
+
+const P3_EXAMPLE5: &str = "\
+    SSS.S\n\
+    .....\n\
+    #.#.#\n\
+    .#.#.\n\
+    #.D.#\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, P1_EXAMPLE, "27").with_params(&[("moves", "3")]),
+    Example::new(2, P2_EXAMPLE, "27").with_params(&[("rounds", "3")]),
+    Example::new(3, P3_EXAMPLE1, "15"),
+    Example::new(3, P3_EXAMPLE2, "8"),
+    Example::new(3, P3_EXAMPLE3, "44"),
+    Example::new(3, P3_EXAMPLE4, "4406"),
+    Example::new(3, P3_EXAMPLE5, "13033988838"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-    use test_case::test_case;
-
-    const P1_EXAMPLE: &str = "\
-        ...SSS.......\n\
-        .S......S.SS.\n\
-        ..S....S...S.\n\
-        ..........SS.\n\
-        ..SSSS...S...\n\
-        .....SS..S..S\n\
-        SS....D.S....\n\
-        S.S..S..S....\n\
-        ....S.......S\n\
-        .SSS..SS.....\n\
-        .........S...\n\
-        .......S....S\n\
-        SS.....S..S..\
-    ";
-
-    const P2_EXAMPLE: &str = "\
-        ...SSS##.....\n\
-        .S#.##..S#SS.\n\
-        ..S.##.S#..S.\n\
-        .#..#S##..SS.\n\
-        ..SSSS.#.S.#.\n\
-        .##..SS.#S.#S\n\
-        SS##.#D.S.#..\n\
-        S.S..S..S###.\n\
-        .##.S#.#....S\n\
-        .SSS.#SS..##.\n\
-        ..#.##...S##.\n\
-        .#...#.S#...S\n\
-        SS...#.S.#S..\
-    ";
-
-    const P3_EXAMPLE1: &str = "\
-        SSS\n\
-        ..#\n\
-        #.#\n\
-        #D.\
-    ";
-
-    const P3_EXAMPLE2: &str = "\
-        SSS\n\
-        ..#\n\
-        ..#\n\
-        .##\n\
-        .D#\
-    ";
-
-    const P3_EXAMPLE3: &str = "\
-        ..S..\n\
-        .....\n\
-        ..#..\n\
-        .....\n\
-        ..D..\
-    ";
-
-    const P3_EXAMPLE4: &str = "\
-        .SS.S\n\
-        #...#\n\
-        ...#.\n\
-        ##..#\n\
-        .####\n\
-        ##D.#\
-    ";
-
-    const P3_EXAMPLE5: &str = "\
-        SSS.S\n\
-        .....\n\
-        #.#.#\n\
-        .#.#.\n\
-        #.D.#\
-    ";
-
-    #[test]
-    fn test_part_1() {
-        let board = Day10::parse(P1_EXAMPLE).unwrap();
-        let result = StaticSheep::new(&board).reachable_static_sheep(3);
-        assert_eq!(result, 27);
-    }
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_2() {
-        let board = Day10::parse(P2_EXAMPLE).unwrap();
-        let result = DynamicSheep::new(&board).reachable_moving_sheep(3);
-        assert_eq!(result, 27);
-    }
-
-    #[test_case(P3_EXAMPLE1 => 15)]
-    #[test_case(P3_EXAMPLE2 => 8)]
-    #[test_case(P3_EXAMPLE3 => 44)]
-    #[test_case(P3_EXAMPLE4 => 4_406)]
-    #[test_case(P3_EXAMPLE5 => 13_033_988_838)]
-    fn test_part_3(input: &str) -> usize {
-        let board = Day10::parse(input).unwrap();
-        Game::new(&board).count_winning_games()
+    fn test_examples() {
+        check_examples::<Day10>(EXAMPLES);
     }
 }
@@ -1,5 +1,7 @@
 use std::num::ParseIntError;
 
+use crate::registry::Example;
+
 fn phase_1(nums: &mut [u64]) -> bool {
     let mut any_change = false;
     for i in 0..nums.len() - 1 {
@@ -37,6 +39,10 @@ impl crate::Day for Day11 {
 
     type ParseError = ParseIntError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.lines().map(str::parse).collect()
     }
@@ -67,34 +73,26 @@ impl crate::Day for Day11 {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::Day;
-    use test_case::test_case;
+const EXAMPLE1: &str = "9\n1\n1\n4\n9\n6";
 
-    const EXAMPLE1: &str = "9\n1\n1\n4\n9\n6";
-    const EXAMPLE2: &str = "805\n706\n179\n48\n158\n150\n232\n885\n598\n524\n423";
+const EXAMPLE2: &str = "805\n706\n179\n48\n158\n150\n232\n885\n598\n524\n423";
 
-    const EXAMPLE2_SORTED: &str = "48\n150\n158\n179\n232\n423\n524\n598\n706\n805\n885";
+const EXAMPLE2_SORTED: &str = "48\n150\n158\n179\n232\n423\n524\n598\n706\n805\n885";
 
-    #[test]
-    fn test_part_1() {
-        let input = Day11::parse(EXAMPLE1).unwrap();
-        let result = Day11::part_1(&input);
-        assert_eq!(result, 109);
-    }
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "109"),
+    Example::new(2, EXAMPLE1, "11"),
+    Example::new(2, EXAMPLE2, "1579"),
+    Example::new(3, EXAMPLE2_SORTED, "1378"),
+];
 
-    #[test_case(EXAMPLE1 => 11)]
-    #[test_case(EXAMPLE2 => 1579)]
-    fn test_part_2(input: &str) -> u64 {
-        let input = Day11::parse(input).unwrap();
-        Day11::part_2(&input)
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
 
-    #[test_case(EXAMPLE2_SORTED => 1378)]
-    fn test_part_3(input: &str) -> u64 {
-        let input = Day11::parse(input).unwrap();
-        Day11::part_3(&input)
+    #[test]
+    fn test_examples() {
+        check_examples::<Day11>(EXAMPLES);
     }
 }
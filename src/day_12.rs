@@ -4,6 +4,8 @@ use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -27,6 +29,18 @@ impl<T> Grid<T> {
             height,
         }
     }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): (usize, usize)) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.data[r * self.width + c])
+    }
 }
 
 impl FromStr for Grid<u8> {
@@ -107,6 +121,10 @@ impl crate::Day for Day12 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
@@ -170,63 +188,53 @@ impl crate::Day for Day12 {
     }
 }
 
+const EXAMPLE1: &str = "\
+    989611\n\
+    857782\n\
+    746543\n\
+    766789\
+";
+
+const EXAMPLE2: &str = "\
+    9589233445\n\
+    9679121695\n\
+    8469121876\n\
+    8352919876\n\
+    7342914327\n\
+    7234193437\n\
+    6789193538\n\
+    6781219648\n\
+    5691219769\n\
+    5443329859\
+";
+
+const EXAMPLE3: &str = "\
+    41951111131882511179\n\
+    32112222211508122215\n\
+    31223333322105122219\n\
+    31234444432147511128\n\
+    91223333322176021892\n\
+    60112222211166431583\n\
+    04661111166111111746\n\
+    01111119042122222177\n\
+    41222108881233333219\n\
+    71222127839122222196\n\
+    56111026279711111507\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "16"),
+    Example::new(2, EXAMPLE2, "58"),
+    Example::new(3, EXAMPLE3, "133"),
+];
+
 #[cfg(test)]
-mod test {
+mod tests {
     use super::*;
-    use crate::Day;
-
-    const EXAMPLE1: &str = "\
-        989611\n\
-        857782\n\
-        746543\n\
-        766789\
-    ";
-
-    const EXAMPLE2: &str = "\
-        9589233445\n\
-        9679121695\n\
-        8469121876\n\
-        8352919876\n\
-        7342914327\n\
-        7234193437\n\
-        6789193538\n\
-        6781219648\n\
-        5691219769\n\
-        5443329859\
-    ";
-
-    const EXAMPLE3: &str = "\
-        41951111131882511179\n\
-        32112222211508122215\n\
-        31223333322105122219\n\
-        31234444432147511128\n\
-        91223333322176021892\n\
-        60112222211166431583\n\
-        04661111166111111746\n\
-        01111119042122222177\n\
-        41222108881233333219\n\
-        71222127839122222196\n\
-        56111026279711111507\
-    ";
-
-    #[test]
-    fn test_part_1() {
-        let input = Day12::parse(EXAMPLE1).unwrap();
-        let result = Day12::part_1(&input);
-        assert_eq!(result, 16);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = Day12::parse(EXAMPLE2).unwrap();
-        let result = Day12::part_2(&input);
-        assert_eq!(result, 58);
-    }
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_3() {
-        let input = Day12::parse(EXAMPLE3).unwrap();
-        let result = Day12::part_3(&input);
-        assert_eq!(result, 133);
+    fn test_examples() {
+        check_examples::<Day12>(EXAMPLES);
     }
 }
@@ -1,6 +1,9 @@
 use std::num::ParseIntError;
 use std::str::FromStr;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValueRange {
     start: u64,
@@ -8,6 +11,15 @@ pub struct ValueRange {
 }
 
 impl ValueRange {
+    pub const fn start(self) -> u64 {
+        self.start
+    }
+
+    /// The last value, inclusive
+    pub const fn end(self) -> u64 {
+        self.end
+    }
+
     const fn len(self) -> u64 {
         self.end - self.start + 1
     }
@@ -46,6 +58,11 @@ pub struct Wheel {
 }
 
 impl Wheel {
+    /// The ranges in the order they are listed, before being placed around the wheel
+    pub fn values(&self) -> &[ValueRange] {
+        &self.values
+    }
+
     fn spin(&self, ticks: u64) -> u64 {
         let len = self.values.iter().map(|r| r.len()).sum::<u64>();
         let mut ix = ticks % (len + 1);
@@ -81,53 +98,88 @@ impl FromStr for Wheel {
 
 pub struct Day13;
 
+/// The number of turns of the wheel, by default 2025, 20252025 and 202520252025 in the parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Params {
+    pub turns: Option<u64>,
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "turns" => self.turns = Some(parse_value(name, value)?),
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day13 {
     type Input = Wheel;
     type ParseError = ParseIntError;
+    type Params = Params;
+    const PARTS: &[u16] = &[1, 2, 3];
 
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
 
     fn part_1(input: &Self::Input) -> u64 {
-        input.spin(2025)
+        Self::part_1_with(input, &Params::default())
     }
 
     fn part_2(input: &Self::Input) -> u64 {
-        input.spin(20_252_025)
+        Self::part_2_with(input, &Params::default())
     }
 
     fn part_3(input: &Self::Input) -> u64 {
-        input.spin(202_520_252_025)
+        Self::part_3_with(input, &Params::default())
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> u64 {
+        input.spin(params.turns.unwrap_or(2025))
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> u64 {
+        input.spin(params.turns.unwrap_or(20_252_025))
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Params) -> u64 {
+        input.spin(params.turns.unwrap_or(202_520_252_025))
     }
 }
 
+const EXAMPLE1_A: &str = "72\n58\n47\n61\n67";
+
+const EXAMPLE1_B: &str = "72\n58\n47\n61\n67\n2";
+
+const EXAMPLE1_C: &str = "72\n58\n47\n61\n67\n2\n3\n4\n5\n6\n7";
+
+const EXAMPLE1_D: &str = "72\n58\n47\n61\n67\n2\n3\n4\n5\n6\n7\n8";
+
+const EXAMPLE2: &str = "\
+    10-15\n\
+    12-13\n\
+    20-21\n\
+    19-23\n\
+    30-37\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1_A, "67"),
+    Example::new(1, EXAMPLE1_B, "47"),
+    Example::new(1, EXAMPLE1_C, "2"),
+    Example::new(1, EXAMPLE1_D, "2"),
+    Example::new(2, EXAMPLE2, "30"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-    use test_case::test_case;
-
-    #[test_case("72\n58\n47\n61\n67" => 67)]
-    #[test_case("72\n58\n47\n61\n67\n2" => 47)]
-    #[test_case("72\n58\n47\n61\n67\n2\n3\n4\n5\n6\n7" => 2)]
-    #[test_case("72\n58\n47\n61\n67\n2\n3\n4\n5\n6\n7\n8" => 2)]
-    fn test_part_1(input: &str) -> u64 {
-        let parsed = Day13::parse(input).unwrap();
-        Day13::part_1(&parsed)
-    }
-
-    const EXAMPLE2: &str = "\
-        10-15\n\
-        12-13\n\
-        20-21\n\
-        19-23\n\
-        30-37\
-    ";
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_2() {
-        let parsed = Day13::parse(EXAMPLE2).unwrap();
-        assert_eq!(Day13::part_2(&parsed), 30);
+    fn test_examples() {
+        check_examples::<Day13>(EXAMPLES);
     }
 }
@@ -4,6 +4,9 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -47,6 +50,18 @@ impl<T> Grid<T> {
         }
     }
 
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): (usize, usize)) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.data[r * self.width + c])
+    }
+
     fn row(&self, r: usize) -> &[T] {
         &self.data[r * self.width..(r + 1) * self.width]
     }
@@ -189,64 +204,101 @@ fn simulate_matches(target: &Grid<Tile>, turns: usize) -> usize {
 
 pub struct Day14;
 
+/// The number of rounds, by default 10, 2025 and 1000000000 in the parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Params {
+    pub rounds: Option<usize>,
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "rounds" => self.rounds = Some(parse_value(name, value)?),
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day14 {
     type Input = Grid<Tile>;
 
     type ParseError = ParseError;
 
+    type Params = Params;
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
 
     fn part_1(input: &Self::Input) -> usize {
-        simulate(input, 10)
+        Self::part_1_with(input, &Params::default())
     }
 
     fn part_2(input: &Self::Input) -> usize {
-        simulate(input, 2025)
+        Self::part_2_with(input, &Params::default())
     }
 
     fn part_3(input: &Self::Input) -> usize {
-        simulate_matches(input, 1_000_000_000)
+        Self::part_3_with(input, &Params::default())
+    }
+
+    fn part_1_with(input: &Self::Input, params: &Params) -> usize {
+        simulate(input, params.rounds.unwrap_or(10))
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Params) -> usize {
+        simulate(input, params.rounds.unwrap_or(2025))
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Params) -> usize {
+        simulate_matches(input, params.rounds.unwrap_or(1_000_000_000))
     }
 }
 
+const EXAMPLE1: &str = "\
+    .#.##.\n\
+    ##..#.\n\
+    ..##.#\n\
+    .#.##.\n\
+    .###..\n\
+    ###.##\
+";
+
+const EXAMPLE2: &str = "\
+    #......#\n\
+    ..#..#..\n\
+    .##..##.\n\
+    ...##...\n\
+    ...##...\n\
+    .##..##.\n\
+    ..#..#..\n\
+    #......#\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "200"),
+    Example::new(3, EXAMPLE2, "278388552"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Day;
+    use crate::registry::check_examples;
 
-    const EXAMPLE1: &str = "\
-        .#.##.\n\
-        ##..#.\n\
-        ..##.#\n\
-        .#.##.\n\
-        .###..\n\
-        ###.##\
-    ";
-
-    const EXAMPLE2: &str = "\
-        #......#\n\
-        ..#..#..\n\
-        .##..##.\n\
-        ...##...\n\
-        ...##...\n\
-        .##..##.\n\
-        ..#..#..\n\
-        #......#\
-    ";
-
+    /// Not an example of the quest: part 2 with the rounds of part 1 has to agree with it.
     #[test]
-    fn test_part_1() {
+    fn test_part_2_rounds() {
         let input = Day14::parse(EXAMPLE1).unwrap();
-        let result = Day14::part_1(&input);
+        let result = Day14::part_2_with(&input, &Params { rounds: Some(10) });
         assert_eq!(result, 200);
     }
 
     #[test]
-    fn test_part_3() {
-        let input = Day14::parse(EXAMPLE2).unwrap();
-        let result = Day14::part_3(&input);
-        assert_eq!(result, 278_388_552);
+    fn test_examples() {
+        check_examples::<Day14>(EXAMPLES);
     }
 }
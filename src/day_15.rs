@@ -5,6 +5,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -269,6 +271,10 @@ impl crate::Day for Day15 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.split(',').map(str::parse).collect()
     }
@@ -286,23 +292,20 @@ impl crate::Day for Day15 {
     }
 }
 
+const EXAMPLE1: &str = "L6,L3,L6,R3,L6,L3,L3,R6,L6,R6,L6,L6,R3,L3,L3,R3,R3,L6,L6,L3";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "16"),
+    Example::new(3, EXAMPLE1, "16"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-
-    const EXAMPLE1: &str = "L6,L3,L6,R3,L6,L3,L3,R6,L6,R6,L6,L6,R3,L3,L3,R3,R3,L6,L6,L3";
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_1() {
-        let input = Day15::parse(EXAMPLE1).unwrap();
-        let result = Day15::part_1(&input);
-        assert_eq!(result, 16);
-    }
-    #[test]
-    fn test_part_3() {
-        let input = Day15::parse(EXAMPLE1).unwrap();
-        let result = Day15::part_3(&input);
-        assert_eq!(result, 16);
+    fn test_examples() {
+        check_examples::<Day15>(EXAMPLES);
     }
 }
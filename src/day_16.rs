@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
 use std::num::ParseIntError;
 
+use crate::params::{ParamError, parse_value};
+use crate::registry::Example;
+
 fn bricks_for_wall_length(spell: &[u64], wall_length: u64) -> u64 {
     spell.iter().map(|&x| wall_length / x).sum()
 }
@@ -22,11 +25,39 @@ fn spell_for_wall(wall: &[u64]) -> Vec<u64> {
 
 pub struct Day16;
 
+/// The number of blocks available for the wall in part 3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub blocks: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            blocks: 202_520_252_025_000,
+        }
+    }
+}
+
+impl crate::params::Params for Params {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "blocks" => self.blocks = parse_value(name, value)?,
+            _ => return Err(ParamError::Unknown(name.to_string())),
+        }
+        Ok(())
+    }
+}
+
 impl crate::Day for Day16 {
     type Input = Vec<u64>;
 
     type ParseError = ParseIntError;
 
+    type Params = Params;
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.split(',').map(str::parse).collect()
     }
@@ -40,8 +71,12 @@ impl crate::Day for Day16 {
     }
 
     fn part_3(input: &Self::Input) -> u64 {
+        Self::part_3_with(input, &Params::default())
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Params) -> u64 {
         let spell = spell_for_wall(input);
-        let target = 202_520_252_025_000;
+        let target = params.blocks;
         let mut high = 1;
         while bricks_for_wall_length(&spell, high) < target {
             high *= 2;
@@ -60,29 +95,23 @@ impl crate::Day for Day16 {
     }
 }
 
+const EXAMPLE1: &str = "1,2,3,5,9";
+
+const EXAMPLE2: &str = "1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "193"),
+    Example::new(2, EXAMPLE2, "270"),
+    Example::new(3, EXAMPLE2, "94439495762954"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-
-    #[test]
-    fn test_part_1() {
-        let input = Day16::parse("1,2,3,5,9").unwrap();
-        let result = Day16::part_1(&input);
-        assert_eq!(result, 193);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = Day16::parse("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2").unwrap();
-        let result = Day16::part_2(&input);
-        assert_eq!(result, 270);
-    }
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_3() {
-        let input = Day16::parse("1,2,2,2,2,3,1,2,3,3,1,3,1,2,3,2,1,4,1,3,2,2,1,3,2,2").unwrap();
-        let result = Day16::part_3(&input);
-        assert_eq!(result, 94_439_495_762_954);
+    fn test_examples() {
+        check_examples::<Day16>(EXAMPLES);
     }
 }
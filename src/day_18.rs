@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -19,6 +21,19 @@ pub struct Plant {
 }
 
 impl Plant {
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    pub const fn thickness(&self) -> u64 {
+        self.thickness
+    }
+
+    /// The incoming branches, empty for a free plant
+    pub fn branches(&self) -> &[Branch] {
+        &self.branches
+    }
+
     const fn is_free(&self) -> bool {
         self.branches.is_empty()
     }
@@ -64,6 +79,16 @@ pub struct Branch {
     connected_to: usize,
 }
 
+impl Branch {
+    pub const fn thickness(self) -> i64 {
+        self.thickness
+    }
+
+    pub const fn connected_to(self) -> usize {
+        self.connected_to
+    }
+}
+
 impl FromStr for Branch {
     type Err = ParseError;
 
@@ -89,6 +114,15 @@ pub struct Input {
 }
 
 impl Input {
+    pub fn plants(&self) -> &[Plant] {
+        &self.plants
+    }
+
+    /// The activations of the free plants, one bit each, with plant 1 as the lowest bit
+    pub fn test_cases(&self) -> &[u128] {
+        &self.test_cases
+    }
+
     fn final_plant_energy(&self, configuration: u128, energy: &mut Vec<i64>) -> i64 {
         energy.clear();
         for plant in &self.plants {
@@ -143,6 +177,10 @@ impl crate::Day for Day18 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
@@ -201,122 +239,112 @@ impl crate::Day for Day18 {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::Day;
-
-    const EXAMPLE1: &str = "\
-        Plant 1 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 2 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 3 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 4 with thickness 17:\n\
-        - branch to Plant 1 with thickness 15\n\
-        - branch to Plant 2 with thickness 3\n\
-        \n\
-        Plant 5 with thickness 24:\n\
-        - branch to Plant 2 with thickness 11\n\
-        - branch to Plant 3 with thickness 13\n\
-        \n\
-        Plant 6 with thickness 15:\n\
-        - branch to Plant 3 with thickness 14\n\
-        \n\
-        Plant 7 with thickness 10:\n\
-        - branch to Plant 4 with thickness 15\n\
-        - branch to Plant 5 with thickness 21\n\
-        - branch to Plant 6 with thickness 34\
-    ";
+const EXAMPLE1: &str = "\
+    Plant 1 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 2 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 3 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 4 with thickness 17:\n\
+    - branch to Plant 1 with thickness 15\n\
+    - branch to Plant 2 with thickness 3\n\
+    \n\
+    Plant 5 with thickness 24:\n\
+    - branch to Plant 2 with thickness 11\n\
+    - branch to Plant 3 with thickness 13\n\
+    \n\
+    Plant 6 with thickness 15:\n\
+    - branch to Plant 3 with thickness 14\n\
+    \n\
+    Plant 7 with thickness 10:\n\
+    - branch to Plant 4 with thickness 15\n\
+    - branch to Plant 5 with thickness 21\n\
+    - branch to Plant 6 with thickness 34\
+";
 
-    #[test]
-    fn test_part_1() {
-        let input = Day18::parse(EXAMPLE1).unwrap();
-        let result = Day18::part_1(&input);
-        assert_eq!(result, 774);
-    }
+const EXAMPLE2: &str = "\
+    Plant 1 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 2 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 3 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 4 with thickness 10:\n\
+    - branch to Plant 1 with thickness -25\n\
+    - branch to Plant 2 with thickness 17\n\
+    - branch to Plant 3 with thickness 12\n\
+    \n\
+    Plant 5 with thickness 14:\n\
+    - branch to Plant 1 with thickness 14\n\
+    - branch to Plant 2 with thickness -26\n\
+    - branch to Plant 3 with thickness 15\n\
+    \n\
+    Plant 6 with thickness 150:\n\
+    - branch to Plant 4 with thickness 5\n\
+    - branch to Plant 5 with thickness 6\n\
+    \n\
+    \n\
+    1 0 1\n\
+    0 0 1\n\
+    0 1 1\
+";
 
-    const EXAMPLE2: &str = "\
-        Plant 1 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 2 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 3 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 4 with thickness 10:\n\
-        - branch to Plant 1 with thickness -25\n\
-        - branch to Plant 2 with thickness 17\n\
-        - branch to Plant 3 with thickness 12\n\
-        \n\
-        Plant 5 with thickness 14:\n\
-        - branch to Plant 1 with thickness 14\n\
-        - branch to Plant 2 with thickness -26\n\
-        - branch to Plant 3 with thickness 15\n\
-        \n\
-        Plant 6 with thickness 150:\n\
-        - branch to Plant 4 with thickness 5\n\
-        - branch to Plant 5 with thickness 6\n\
-        \n\
-        \n\
-        1 0 1\n\
-        0 0 1\n\
-        0 1 1\
-    ";
+const EXAMPLE3: &str = "\
+    Plant 1 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 2 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 3 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 4 with thickness 1:\n\
+    - free branch with thickness 1\n\
+    \n\
+    Plant 5 with thickness 8:\n\
+    - branch to Plant 1 with thickness -8\n\
+    - branch to Plant 2 with thickness 11\n\
+    - branch to Plant 3 with thickness 13\n\
+    - branch to Plant 4 with thickness -7\n\
+    \n\
+    Plant 6 with thickness 7:\n\
+    - branch to Plant 1 with thickness 14\n\
+    - branch to Plant 2 with thickness -9\n\
+    - branch to Plant 3 with thickness 12\n\
+    - branch to Plant 4 with thickness 9\n\
+    \n\
+    Plant 7 with thickness 23:\n\
+    - branch to Plant 5 with thickness 17\n\
+    - branch to Plant 6 with thickness 18\n\
+    \n\
+    \n\
+    0 1 0 0\n\
+    0 1 0 1\n\
+    0 1 1 1\n\
+    1 1 0 1\
+";
 
-    #[test]
-    fn test_part_2() {
-        let input = Day18::parse(EXAMPLE2).unwrap();
-        let result = Day18::part_2(&input);
-        assert_eq!(result, 324);
-    }
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "774"),
+    Example::new(2, EXAMPLE2, "324"),
+    Example::new(3, EXAMPLE3, "946"),
+];
 
-    const EXAMPLE3: &str = "\
-        Plant 1 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 2 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 3 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 4 with thickness 1:\n\
-        - free branch with thickness 1\n\
-        \n\
-        Plant 5 with thickness 8:\n\
-        - branch to Plant 1 with thickness -8\n\
-        - branch to Plant 2 with thickness 11\n\
-        - branch to Plant 3 with thickness 13\n\
-        - branch to Plant 4 with thickness -7\n\
-        \n\
-        Plant 6 with thickness 7:\n\
-        - branch to Plant 1 with thickness 14\n\
-        - branch to Plant 2 with thickness -9\n\
-        - branch to Plant 3 with thickness 12\n\
-        - branch to Plant 4 with thickness 9\n\
-        \n\
-        Plant 7 with thickness 23:\n\
-        - branch to Plant 5 with thickness 17\n\
-        - branch to Plant 6 with thickness 18\n\
-        \n\
-        \n\
-        0 1 0 0\n\
-        0 1 0 1\n\
-        0 1 1 1\n\
-        1 1 0 1\
-    ";
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_3() {
-        let input = Day18::parse(EXAMPLE3).unwrap();
-        let result = Day18::part_3(&input);
-        assert_eq!(result, 946);
+    fn test_examples() {
+        check_examples::<Day18>(EXAMPLES);
     }
 }
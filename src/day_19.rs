@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Syntax error")]
@@ -19,6 +21,22 @@ pub struct Opening {
     height: i64,
 }
 
+impl Opening {
+    /// Distance from the start to the wall
+    pub const fn ahead(self) -> u64 {
+        self.ahead
+    }
+
+    /// Height of the bottom of the opening
+    pub const fn start(self) -> i64 {
+        self.start
+    }
+
+    pub const fn height(self) -> i64 {
+        self.height
+    }
+}
+
 impl FromStr for Opening {
     type Err = ParseError;
 
@@ -111,6 +129,10 @@ impl crate::Day for Day19 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.lines().map(str::parse).collect()
     }
@@ -128,42 +150,38 @@ impl crate::Day for Day19 {
     }
 }
 
+const EXAMPLE1: &str = "\
+    7,7,2\n\
+    12,0,4\n\
+    15,5,3\n\
+    24,1,6\n\
+    28,5,5\n\
+    40,8,2\
+";
+
+const EXAMPLE2: &str = "\
+    7,7,2\n\
+    7,1,3\n\
+    12,0,4\n\
+    15,5,3\n\
+    24,1,6\n\
+    28,5,5\n\
+    40,3,3\n\
+    40,8,2\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "24"),
+    Example::new(2, EXAMPLE2, "22"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-
-    const EXAMPLE1: &str = "\
-        7,7,2\n\
-        12,0,4\n\
-        15,5,3\n\
-        24,1,6\n\
-        28,5,5\n\
-        40,8,2\
-    ";
-
-    #[test]
-    fn test_part_1() {
-        let input = Day19::parse(EXAMPLE1).unwrap();
-        let result = Day19::part_1(&input);
-        assert_eq!(result, 24);
-    }
-
-    const EXAMPLE2: &str = "\
-        7,7,2\n\
-        7,1,3\n\
-        12,0,4\n\
-        15,5,3\n\
-        24,1,6\n\
-        28,5,5\n\
-        40,3,3\n\
-        40,8,2\
-    ";
+    use crate::registry::check_examples;
 
     #[test]
-    fn test_part_2() {
-        let input = Day19::parse(EXAMPLE2).unwrap();
-        let result = Day19::part_2(&input);
-        assert_eq!(result, 22);
+    fn test_examples() {
+        check_examples::<Day19>(EXAMPLES);
     }
 }
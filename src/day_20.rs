@@ -5,6 +5,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use crate::registry::Example;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
     Up,
@@ -28,8 +30,9 @@ impl Direction {
     }
 }
 
+/// Which of the two triangles sharing a column and row a position refers to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Parity {
+pub enum Parity {
     L,
     R,
 }
@@ -47,18 +50,31 @@ impl Parity {
     }
 }
 
+/// A triangle in the grid, by column `q`, row `r`, and which of the pair it is.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Pos {
+pub struct Pos {
     q: usize,
     r: usize,
     parity: Parity,
 }
 
 impl Pos {
-    const fn new(q: usize, r: usize, parity: Parity) -> Self {
+    pub const fn new(q: usize, r: usize, parity: Parity) -> Self {
         Self { q, r, parity }
     }
 
+    pub const fn q(self) -> usize {
+        self.q
+    }
+
+    pub const fn r(self) -> usize {
+        self.r
+    }
+
+    pub const fn parity(self) -> Parity {
+        self.parity
+    }
+
     const fn into_index(self, size: usize) -> usize {
         2 * self.q + self.parity.into_index() + self.r * (2 * size - self.r)
     }
@@ -108,13 +124,23 @@ pub struct TriangularGrid<T> {
 }
 
 impl<T> TriangularGrid<T> {
-    #[allow(unused, reason = "only used in tests")]
     pub fn new(data: Vec<T>, size: usize) -> Self {
         assert_eq!(data.len(), size * size, "data.len() == size * size");
         Self { data, size }
     }
 
-    fn positions(&self) -> impl Iterator<Item = Pos> {
+    /// Side length of the triangle
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        pos.within_grid(self.size)
+            .then(|| &self.data[pos.into_index(self.size)])
+    }
+
+    /// All positions within the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
         (0..self.size).flat_map(|r| {
             (0..self.size - r - 1)
                 .flat_map(move |q| [Pos::new(q, r, Parity::L), Pos::new(q, r, Parity::R)])
@@ -299,6 +325,10 @@ impl crate::Day for Day20 {
 
     type ParseError = ParseError;
 
+    type Params = ();
+
+    const PARTS: &[u16] = &[1, 2, 3];
+
     fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
         input.parse()
     }
@@ -339,11 +369,70 @@ impl crate::Day for Day20 {
     }
 }
 
+const EXAMPLE1_A: &str = "\
+    T#TTT###T##\n\
+    .##TT#TT##.\n\
+    ..T###T#T..\n\
+    ...##TT#...\n\
+    ....T##....\n\
+    .....#.....\
+";
+
+const EXAMPLE1_B: &str = "\
+    T#T#T#T#T#T\n\
+    .T#T#T#T#T.\n\
+    ..T#T#T#T..\n\
+    ...T#T#T...\n\
+    ....T#T....\n\
+    .....T.....\
+";
+
+const EXAMPLE1_C: &str = "\
+    T#T#T#T#T#T\n\
+    .#T#T#T#T#.\n\
+    ..#T###T#..\n\
+    ...##T##...\n\
+    ....#T#....\n\
+    .....#.....\
+";
+
+const EXAMPLE2: &str = "\
+    TTTTTTTTTTTTTTTTT\n\
+    .TTTT#T#T#TTTTTT.\n\
+    ..TT#TTTETT#TTT..\n\
+    ...TT#T#TTT#TT...\n\
+    ....TTT#T#TTT....\n\
+    .....TTTTTT#.....\n\
+    ......TT#TT......\n\
+    .......#TT.......\n\
+    ........S........\
+";
+
+const EXAMPLE3: &str = "\
+    T####T#TTT##T##T#T#\n\
+    .T#####TTTT##TTT##.\n\
+    ..TTTT#T###TTTT#T..\n\
+    ...T#TTT#ETTTT##...\n\
+    ....#TT##T#T##T....\n\
+    .....#TT####T#.....\n\
+    ......T#TT#T#......\n\
+    .......T#TTT.......\n\
+    ........TT#........\n\
+    .........S.........\
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1_A, "7"),
+    Example::new(1, EXAMPLE1_B, "0"),
+    Example::new(1, EXAMPLE1_C, "0"),
+    Example::new(2, EXAMPLE2, "32"),
+    Example::new(3, EXAMPLE3, "23"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Day;
-    use test_case::test_case;
+    use crate::registry::check_examples;
 
     #[test]
     fn test_triangle_positions() {
@@ -366,6 +455,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get() {
+        let grid = TriangularGrid::new((0..9).collect(), 3);
+        assert_eq!(grid.get(Pos::new(1, 1, Parity::L)), Some(&7));
+        assert_eq!(grid.get(Pos::new(1, 1, Parity::R)), None);
+        assert_eq!(grid.get(Pos::new(0, 3, Parity::L)), None);
+    }
+
     #[test]
     fn test_rotation() {
         let positons: Vec<Pos> = TriangularGrid::<()>::new(vec![(); 3 * 3], 3)
@@ -388,77 +485,8 @@ mod tests {
         );
     }
 
-    const EXAMPLE1_A: &str = "\
-        T#TTT###T##\n\
-        .##TT#TT##.\n\
-        ..T###T#T..\n\
-        ...##TT#...\n\
-        ....T##....\n\
-        .....#.....\
-    ";
-
-    const EXAMPLE1_B: &str = "\
-        T#T#T#T#T#T\n\
-        .T#T#T#T#T.\n\
-        ..T#T#T#T..\n\
-        ...T#T#T...\n\
-        ....T#T....\n\
-        .....T.....\
-    ";
-
-    const EXAMPLE1_C: &str = "\
-        T#T#T#T#T#T\n\
-        .#T#T#T#T#.\n\
-        ..#T###T#..\n\
-        ...##T##...\n\
-        ....#T#....\n\
-        .....#.....\
-    ";
-
-    #[test_case(EXAMPLE1_A => 7)]
-    #[test_case(EXAMPLE1_B => 0)]
-    #[test_case(EXAMPLE1_C => 0)]
-    fn test_part_1(input: &str) -> usize {
-        let triangles = Day20::parse(input).unwrap();
-        Day20::part_1(&triangles)
-    }
-
-    const EXAMPLE2: &str = "\
-        TTTTTTTTTTTTTTTTT\n\
-        .TTTT#T#T#TTTTTT.\n\
-        ..TT#TTTETT#TTT..\n\
-        ...TT#T#TTT#TT...\n\
-        ....TTT#T#TTT....\n\
-        .....TTTTTT#.....\n\
-        ......TT#TT......\n\
-        .......#TT.......\n\
-        ........S........\
-    ";
-
-    #[test]
-    fn test_part_2() {
-        let grid = Day20::parse(EXAMPLE2).unwrap();
-        let result = Day20::part_2(&grid);
-        assert_eq!(result, 32);
-    }
-
-    const EXAMPLE3: &str = "\
-        T####T#TTT##T##T#T#\n\
-        .T#####TTTT##TTT##.\n\
-        ..TTTT#T###TTTT#T..\n\
-        ...T#TTT#ETTTT##...\n\
-        ....#TT##T#T##T....\n\
-        .....#TT####T#.....\n\
-        ......T#TT#T#......\n\
-        .......T#TTT.......\n\
-        ........TT#........\n\
-        .........S.........\
-    ";
-
     #[test]
-    fn test_part_3() {
-        let grid = Day20::parse(EXAMPLE3).unwrap();
-        let result = Day20::part_3(&grid);
-        assert_eq!(result, 23);
+    fn test_examples() {
+        check_examples::<Day20>(EXAMPLES);
     }
 }
@@ -0,0 +1,242 @@
+use std::fmt::Write;
+
+/// A quest description converted from HTML, along with the examples it contains.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Description {
+    pub markdown: String,
+    pub examples: Vec<DescribedExample>,
+}
+
+/// A preformatted block of the description, and the answer given for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescribedExample {
+    pub input: String,
+    /// The last highlighted value between this block and the next, if any
+    pub expected: Option<String>,
+}
+
+impl Description {
+    /// Converts the subset of HTML used by the quest descriptions. Unknown tags are dropped,
+    /// keeping their text.
+    pub fn from_html(html: &str) -> Self {
+        let mut description = Self::default();
+        let mut markdown = String::new();
+        let mut block = None::<String>;
+        let mut highlight = None::<String>;
+        let mut rest = html;
+        while !rest.is_empty() {
+            let (text, tag) = match rest.find('<') {
+                Some(0) => {
+                    let end = rest.find('>').map_or(rest.len(), |end| end + 1);
+                    let (tag, after) = rest.split_at(end);
+                    rest = after;
+                    ("", Some(tag))
+                }
+                Some(start) => {
+                    let (text, after) = rest.split_at(start);
+                    rest = after;
+                    (text, None)
+                }
+                None => (std::mem::take(&mut rest), None),
+            };
+            let text = decode_entities(text);
+            if let Some(block) = &mut block {
+                block.push_str(&text);
+            } else if !text.is_empty() {
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.starts_with(char::is_whitespace) && !markdown.ends_with([' ', '\n']) {
+                    markdown.push(' ');
+                }
+                markdown.push_str(&collapsed);
+                if let Some(highlight) = &mut highlight {
+                    highlight.push_str(&collapsed);
+                }
+                if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    markdown.push(' ');
+                }
+            }
+            let Some(tag) = tag else {
+                continue;
+            };
+
+            let (name, closing) = tag_name(tag);
+            if let Some(text) = &mut block {
+                match name.as_str() {
+                    "br" => text.push('\n'),
+                    "pre" if closing => {
+                        let input = text.trim_matches('\n').to_string();
+                        write!(markdown, "\n\n```\n{input}\n```\n\n").unwrap();
+                        description.examples.push(DescribedExample {
+                            input,
+                            expected: None,
+                        });
+                        block = None;
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            match name.as_str() {
+                "pre" if !closing => block = Some(String::new()),
+                "br" => markdown.push_str("  \n"),
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if !closing => {
+                    let level = usize::from(name.as_bytes()[1] - b'0');
+                    write!(markdown, "\n\n{} ", "#".repeat(level)).unwrap();
+                }
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" | "div" | "ul" | "ol" => {
+                    markdown.push_str("\n\n");
+                }
+                "li" if !closing => markdown.push_str("\n- "),
+                "i" | "em" => markdown.push('_'),
+                "code" | "b" | "strong" => {
+                    markdown.push_str(if name == "code" { "`" } else { "**" });
+                    if !closing {
+                        highlight = Some(String::new());
+                    } else if let (Some(example), Some(value)) =
+                        (description.examples.last_mut(), highlight.take())
+                    {
+                        example.expected = Some(value);
+                    }
+                }
+                _ => {}
+            }
+        }
+        description.markdown = tidy(&markdown);
+        description
+    }
+}
+
+/// The lowercase name of a tag, and whether it is a closing tag.
+fn tag_name(tag: &str) -> (String, bool) {
+    let inner = tag.trim_start_matches('<').trim_end_matches('>');
+    let closing = inner.starts_with('/');
+    let name = inner
+        .trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    (name, closing)
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .map(|end| &rest[1..end]);
+        let ch = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        if let (Some(ch), Some(entity)) = (ch, entity) {
+            decoded.push(ch);
+            rest = &rest[entity.len() + 2..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Trims the lines, outside of code blocks, and collapses runs of blank lines.
+fn tidy(markdown: &str) -> String {
+    let mut result = String::new();
+    let mut in_block = false;
+    let mut blank = false;
+    for line in markdown.lines() {
+        let line = if in_block { line } else { line.trim() };
+        if line.starts_with("```") {
+            in_block = !in_block;
+        }
+        if line.is_empty() && !in_block {
+            blank = !result.is_empty();
+            continue;
+        }
+        if blank {
+            result.push('\n');
+            blank = false;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#65;&#x42;"),
+            "a <b> & AB"
+        );
+        assert_eq!(
+            decode_entities("fish & chips &unknown;"),
+            "fish & chips &unknown;"
+        );
+    }
+
+    #[test]
+    fn test_from_html() {
+        let html = "<h2>Part I</h2>\
+            <p>The names are <em>listed</em>:</p>\
+            <pre class=\"note\">Vyrdax,Drakzyph\nR3,L2</pre>\
+            <p>The name is <b>Fyrryn</b>, so the answer is <code>Fyrryn</code>.</p>\
+            <p>Another &lt;example&gt;:</p>\
+            <pre>10,5,1</pre>";
+        let description = Description::from_html(html);
+        assert_eq!(
+            description.markdown,
+            "## Part I\n\
+            \n\
+            The names are _listed_:\n\
+            \n\
+            ```\n\
+            Vyrdax,Drakzyph\n\
+            R3,L2\n\
+            ```\n\
+            \n\
+            The name is **Fyrryn**, so the answer is `Fyrryn`.\n\
+            \n\
+            Another <example>:\n\
+            \n\
+            ```\n\
+            10,5,1\n\
+            ```\n"
+        );
+        assert_eq!(
+            description.examples,
+            [
+                DescribedExample {
+                    input: "Vyrdax,Drakzyph\nR3,L2".to_string(),
+                    expected: Some("Fyrryn".to_string()),
+                },
+                DescribedExample {
+                    input: "10,5,1".to_string(),
+                    expected: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,201 @@
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+#![allow(refining_impl_trait)]
+// Internal tooling rather than a published library
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::must_use_candidate,
+    clippy::return_self_not_must_use
+)]
+
+use std::error::Error as StdError;
+use std::fmt::Display;
+
+use thiserror::Error;
+
+pub mod bench;
+pub mod cli;
+pub mod crypto;
+mod description;
+pub mod memory;
+pub mod params;
+pub mod progress;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+/// Output of the scaffold, compiled to have it linted along with the rest
+#[cfg(test)]
+mod scaffold_sample;
+mod watch;
+use crate::params::Params;
+use crate::registry::{DayEntry, Registry};
+use crate::report::Status;
+use crate::runner::{Event, Runner, Sampling};
+
+#[allow(unused)]
+pub trait Day {
+    type Input;
+    type ParseError: StdError;
+    /// Constants of the quest that the examples differ in, `()` if none
+    type Params: Params + Send;
+    /// Parts overriding the `todo!()` defaults below. Only these are run, and shown as done by
+    /// the `list` subcommand.
+    const PARTS: &'static [u16] = &[];
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError>;
+
+    fn part_1(input: &Self::Input) -> impl Display {
+        todo!()
+    }
+
+    fn part_2(input: &Self::Input) -> impl Display {
+        todo!()
+    }
+
+    fn part_3(input: &Self::Input) -> impl Display {
+        todo!()
+    }
+
+    /// Solves part 1 with other parameters than the defaults used by [`Self::part_1`].
+    fn part_1_with(input: &Self::Input, params: &Self::Params) -> impl Display {
+        Self::part_1(input)
+    }
+
+    fn part_2_with(input: &Self::Input, params: &Self::Params) -> impl Display {
+        Self::part_2(input)
+    }
+
+    fn part_3_with(input: &Self::Input, params: &Self::Params) -> impl Display {
+        Self::part_3(input)
+    }
+}
+
+/// Counts the allocations of the tests of the memory tracking. The binary installs its own
+/// with the `mem` feature.
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+// For each day:
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+
+pub const REGISTRY: Registry = Registry {
+    event: Event::Main(2025),
+    days: &[
+        // For each day:
+        DayEntry::new::<day_01::Day01>(1, "Whispers in the Shell").with_examples(day_01::EXAMPLES),
+        DayEntry::new::<day_02::Day02>(2, "From Complex to Clarity")
+            .with_examples(day_02::EXAMPLES),
+        DayEntry::new::<day_03::Day03>(3, "The Deepest Fit").with_examples(day_03::EXAMPLES),
+        DayEntry::new::<day_04::Day04>(4, "Teeth of the Wind").with_examples(day_04::EXAMPLES),
+        DayEntry::new::<day_05::Day05>(5, "Fishbone Order").with_examples(day_05::EXAMPLES),
+        DayEntry::new::<day_06::Day06>(6, "Mentorship Matrix").with_examples(day_06::EXAMPLES),
+        DayEntry::new::<day_07::Day07>(7, "Namegraph").with_examples(day_07::EXAMPLES),
+        DayEntry::new::<day_08::Day08>(8, "The Art of Connection").with_examples(day_08::EXAMPLES),
+        DayEntry::new::<day_09::Day09>(9, "Encoded in the Scales").with_examples(day_09::EXAMPLES),
+        DayEntry::new::<day_10::Day10>(10, "Feast on the Board").with_examples(day_10::EXAMPLES),
+        DayEntry::new::<day_11::Day11>(11, "The Scout Duck Protocol")
+            .with_examples(day_11::EXAMPLES),
+        DayEntry::new::<day_12::Day12>(12, "One Spark to Burn Them All")
+            .with_examples(day_12::EXAMPLES),
+        DayEntry::new::<day_13::Day13>(13, "Unlocking the Mountain")
+            .with_examples(day_13::EXAMPLES),
+        DayEntry::new::<day_14::Day14>(14, "The Game of Light").with_examples(day_14::EXAMPLES),
+        DayEntry::new::<day_15::Day15>(15, "Definitely Not a Maze").with_examples(day_15::EXAMPLES),
+        DayEntry::new::<day_16::Day16>(16, "Spell of Making").with_examples(day_16::EXAMPLES),
+        DayEntry::new::<day_17::Day17>(17, "Deadline-Driven Development")
+            .with_examples(day_17::EXAMPLES),
+        DayEntry::new::<day_18::Day18>(18, "When Roots Remember").with_examples(day_18::EXAMPLES),
+        DayEntry::new::<day_19::Day19>(19, "Flappy Quack").with_examples(day_19::EXAMPLES),
+        DayEntry::new::<day_20::Day20>(20, "Dream in Triangles").with_examples(day_20::EXAMPLES),
+    ],
+};
+
+/// The answer of a part, formatted the way it is submitted.
+pub type Answer = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    #[error("No solution for quest {0}")]
+    UnknownQuest(u16),
+    #[error("Quest {day} part {part} is not implemented")]
+    NotImplemented { day: u16, part: u16 },
+    #[error("Parse error: {0}")]
+    Parse(String),
+    #[error("Solver panicked: {0}")]
+    Panicked(String),
+}
+
+/// Solves a part of a quest of the current event on the given input, with the default
+/// parameters.
+///
+/// Installs the panic hook of the runner on the first call, which keeps the panics of the
+/// solvers out of stderr and leaves the others alone.
+pub fn solve(day: u16, part: u16, input: &str) -> Result<Answer, Error> {
+    runner::install_panic_hook();
+    let entry = REGISTRY.get(day).ok_or(Error::UnknownQuest(day))?;
+    let report = entry.run_on(
+        &Runner::default(),
+        part,
+        input.to_string(),
+        Sampling::repeat(1),
+    );
+    let error = report.error.unwrap_or_default();
+    match (report.status, report.answer) {
+        (Status::Ok, Some(answer)) => Ok(answer),
+        (Status::ParseError, _) => Err(Error::Parse(error)),
+        (Status::NotImplemented, _) => Err(Error::NotImplemented { day, part }),
+        _ => Err(Error::Panicked(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve(3, 1, "10,5,1,10,3,8,5,2,2").as_deref(), Ok("29"));
+        assert_eq!(solve(99, 1, "1"), Err(Error::UnknownQuest(99)));
+        assert_eq!(
+            solve(3, 4, "1"),
+            Err(Error::NotImplemented { day: 3, part: 4 })
+        );
+        assert!(matches!(solve(3, 1, "1,x"), Err(Error::Parse(_))));
+    }
+
+    /// A part left out of [`Day::PARTS`] is skipped everywhere, so each part with examples has
+    /// to be listed. Some parts come without examples.
+    #[test]
+    fn test_registered_parts() {
+        for entry in REGISTRY.days {
+            for example in entry.examples {
+                assert!(
+                    entry.is_implemented(example.part),
+                    "Quest {} part {} has examples, but is missing from PARTS",
+                    entry.day,
+                    example.part
+                );
+            }
+        }
+    }
+}
@@ -1,148 +1,17 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
-#![allow(refining_impl_trait)]
 
-use std::error::Error;
-use std::fmt::Display;
+use std::process::ExitCode;
 
 use clap::Parser;
 
-mod runner;
-use crate::runner::{Cli, Command, Runner};
+use everybody_codes_2025::cli::{self, Cli};
+#[cfg(feature = "mem")]
+use everybody_codes_2025::memory;
 
-#[allow(unused)]
-trait Day {
-    type Input;
-    type ParseError: Error;
-    fn parse(input: &str) -> Result<Self::Input, Self::ParseError>;
+#[cfg(feature = "mem")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
 
-    fn part_1(input: &Self::Input) -> impl Display {
-        todo!()
-    }
-
-    fn part_2(input: &Self::Input) -> impl Display {
-        todo!()
-    }
-
-    fn part_3(input: &Self::Input) -> impl Display {
-        todo!()
-    }
-}
-
-// For each day:
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
-mod day_15;
-mod day_16;
-mod day_17;
-mod day_18;
-mod day_19;
-mod day_20;
-
-fn main() {
-    let mut runner = Runner::default();
-    let cli = Cli::parse();
-    if let Some(cmd) = cli.command {
-        match cmd {
-            Command::Cookie { cookie } => {
-                runner.save_cookie(&cookie);
-            }
-            Command::Download { day } => runner.download(day),
-        }
-    } else {
-        // For each day:
-
-        if cli.day.is_none_or(|d| d == 1) {
-            runner.run::<day_01::Day01>(1, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 2) {
-            runner.run::<day_02::Day02>(2, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 3) {
-            runner.run::<day_03::Day03>(3, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 4) {
-            runner.run::<day_04::Day04>(4, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 5) {
-            runner.run::<day_05::Day05>(5, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 6) {
-            runner.run::<day_06::Day06>(6, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 7) {
-            runner.run::<day_07::Day07>(7, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 8) {
-            runner.run::<day_08::Day08>(8, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 9) {
-            runner.run::<day_09::Day09>(9, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 10) {
-            runner.run::<day_10::Day10>(10, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 11) {
-            runner.run::<day_11::Day11>(11, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 12) {
-            runner.run::<day_12::Day12>(12, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 13) {
-            runner.run::<day_13::Day13>(13, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 14) {
-            runner.run::<day_14::Day14>(14, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 15) {
-            runner.run::<day_15::Day15>(15, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 16) {
-            runner.run::<day_16::Day16>(16, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 17) {
-            runner.run::<day_17::Day17>(17, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 18) {
-            runner.run::<day_18::Day18>(18, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 19) {
-            runner.run::<day_19::Day19>(19, cli.part, cli.repeat);
-        }
-
-        if cli.day.is_none_or(|d| d == 20) {
-            runner.run::<day_20::Day20>(20, cli.part, cli.repeat);
-        }
-
-        println!();
-    }
+fn main() -> ExitCode {
+    cli::main(Cli::parse())
 }
@@ -0,0 +1,161 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes allocated and not freed yet, since tracking was enabled. Negative when more memory
+/// allocated before was freed since.
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// The system allocator, counting the allocations once [`enable`] is called. The counts are
+/// shared by all threads, so parts should not be measured while others are running.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size.cast_signed(), Ordering::Relaxed) + size.cast_signed();
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE.fetch_sub((size as u64).cast_signed(), Ordering::Relaxed);
+    }
+}
+
+// SAFETY: All allocations are forwarded to the system allocator as is.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            Self::record_alloc(layout.size());
+        }
+        // SAFETY: Same contract as the caller
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            Self::record_alloc(layout.size());
+        }
+        // SAFETY: Same contract as the caller
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ENABLED.load(Ordering::Relaxed) {
+            Self::record_dealloc(layout.size());
+        }
+        // SAFETY: Same contract as the caller
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    /// Counted as freeing the old block, and allocating the new one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if ENABLED.load(Ordering::Relaxed) {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        // SAFETY: Same contract as the caller
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Allocations made while running some code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Most bytes allocated at the same time, on top of those allocated before
+    pub peak_bytes: u64,
+}
+
+impl std::fmt::Display for MemStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, peak {}",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Formats a byte count with a binary prefix.
+struct Bytes(u64);
+
+impl std::fmt::Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
+
+/// Runs `f`, counting its allocations if tracking is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemStats>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let result = f();
+    let stats = MemStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: u64::try_from(PEAK.load(Ordering::Relaxed) - live).unwrap_or(0),
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0 => "0 B")]
+    #[test_case(1023 => "1023 B")]
+    #[test_case(1536 => "1.5 KiB")]
+    #[test_case(5 << 20 => "5.0 MiB")]
+    fn test_bytes(bytes: u64) -> String {
+        Bytes(bytes).to_string()
+    }
+
+    #[test]
+    fn test_measure() {
+        enable();
+        let (sum, stats) = measure(|| {
+            let first = vec![0_u8; 1000];
+            drop(first);
+            let second = vec![1_u8; 600];
+            second.iter().map(|&x| u32::from(x)).sum::<u32>()
+        });
+        assert_eq!(sum, 600);
+        let stats = stats.unwrap();
+        // Other tests may allocate at the same time.
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 1600);
+        assert!(stats.peak_bytes >= 1000);
+    }
+}
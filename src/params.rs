@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParamError {
+    #[error("Unknown parameter {0:?}")]
+    Unknown(String),
+    #[error("Invalid value {value:?} for parameter {name}")]
+    InvalidValue { name: String, value: String },
+}
+
+/// Constants of a quest, such as a number of rounds, which the examples use other values for.
+pub trait Params: Default {
+    /// Overrides a single parameter by name.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// The defaults, with the given `(name, value)` overrides.
+    fn from_pairs<'a>(
+        pairs: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, ParamError> {
+        let mut params = Self::default();
+        for (name, value) in pairs {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// For quests without parameters.
+impl Params for () {
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(name.to_string()))
+    }
+}
+
+pub fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value.parse().map_err(|_| ParamError::InvalidValue {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Parses a `name=value` command line argument.
+pub fn parse_pair(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Expected name=value, got {s:?}"))?;
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Eq)]
+    struct Rounds {
+        rounds: Option<u32>,
+    }
+
+    impl Params for Rounds {
+        fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+            match name {
+                "rounds" => self.rounds = Some(parse_value(name, value)?),
+                _ => return Err(ParamError::Unknown(name.to_string())),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_from_pairs() {
+        assert_eq!(Rounds::from_pairs([]), Ok(Rounds { rounds: None }));
+        assert_eq!(
+            Rounds::from_pairs([("rounds", "10")]),
+            Ok(Rounds { rounds: Some(10) })
+        );
+        assert_eq!(
+            Rounds::from_pairs([("rounds", "ten")]),
+            Err(ParamError::InvalidValue {
+                name: "rounds".to_string(),
+                value: "ten".to_string()
+            })
+        );
+        assert_eq!(
+            <()>::from_pairs([("rounds", "10")]),
+            Err(ParamError::Unknown("rounds".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_pair() {
+        assert_eq!(
+            parse_pair("cycles=10"),
+            Ok(("cycles".to_string(), "10".to_string()))
+        );
+        assert!(parse_pair("cycles").is_err());
+    }
+}
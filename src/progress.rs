@@ -0,0 +1,183 @@
+use crate::registry::{Registry, refresh_answers};
+use crate::report::Status;
+use crate::runner::{Runner, RunnerError, Sampling};
+
+/// Completion of a part of a quest, from the answer accepted by the site.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartProgress {
+    pub part: u16,
+    /// Missing while unsolved
+    pub answer: Option<String>,
+    /// Seconds since the unix epoch, when the answer was submitted from here
+    pub completed: Option<u64>,
+}
+
+/// A unix timestamp as `YYYY-MM-DD HH:MM:SS`, in UTC.
+fn format_timestamp(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let time = seconds % 86_400;
+    // Civil from days, shifted so that years start in March, and leap days come last.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = era * 400 + year_of_era + u64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+const HEADER: [&str; 7] = [
+    "Quest",
+    "Part",
+    "Completed",
+    "Accepted",
+    "Answer",
+    "Check",
+    "Time",
+];
+
+/// Pads the columns to the widest cell, with the first two right aligned.
+fn format_table(rows: &[[String; 7]]) -> String {
+    let mut widths = [0; 7];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in rows {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if column < 2 {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:width$}")
+                }
+            })
+            .collect::<Vec<_>>();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// Whether the local answer matches the one accepted by the site.
+fn check(answer: Option<&str>, accepted: Option<&str>) -> &'static str {
+    match (answer, accepted) {
+        (Some(answer), Some(accepted)) if answer == accepted => "ok",
+        (Some(_), Some(_)) => "WRONG",
+        _ => "",
+    }
+}
+
+impl Registry {
+    /// Shows the solved parts with the accepted answers, and runs the solutions on the downloaded
+    /// inputs to check their answers and show their timings. The keys of the quests with unsolved
+    /// parts are refreshed first, keeping the stored answers when that fails.
+    pub fn print_progress(
+        &self,
+        runner: &mut Runner,
+        day_filter: Option<u16>,
+    ) -> Result<(), RunnerError> {
+        let mut rows = vec![HEADER.map(String::from)];
+        let mut refresh = true;
+        for entry in self.days {
+            let day = entry.day;
+            if day_filter.is_some_and(|d| d != day) {
+                continue;
+            }
+            if refresh {
+                refresh = refresh_answers(runner, day, &[1, 2, 3]);
+            }
+            for progress in runner.progress(day)? {
+                let part = progress.part;
+                if progress.answer.is_none() && !entry.is_implemented(part) {
+                    continue;
+                }
+                let (answer, time) = if entry.is_implemented(part) {
+                    let report = entry.run_downloaded(runner, part, Sampling::repeat(1));
+                    match (report.status, report.answer) {
+                        (Status::Ok, Some(answer)) => (Some(answer), Some(report.solve_time)),
+                        (status, _) => (Some(status.as_str().to_string()), None),
+                    }
+                } else {
+                    (None, None)
+                };
+                let solved_locally = answer.as_deref().filter(|_| time.is_some());
+                let check = check(solved_locally, progress.answer.as_deref());
+                rows.push([
+                    day.to_string(),
+                    part.to_string(),
+                    progress.completed.map(format_timestamp).unwrap_or_default(),
+                    progress.answer.unwrap_or_default(),
+                    answer.unwrap_or_default(),
+                    check.to_string(),
+                    time.map(|time| format!("{time:?}")).unwrap_or_default(),
+                ]);
+            }
+        }
+        print!("{}", format_table(&rows));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0 => "1970-01-01 00:00:00")]
+    #[test_case(951_782_400 => "2000-02-29 00:00:00")]
+    #[test_case(1_762_214_399 => "2025-11-03 23:59:59")]
+    fn test_format_timestamp(seconds: u64) -> String {
+        format_timestamp(seconds)
+    }
+
+    #[test]
+    fn test_format_table() {
+        let rows = [
+            HEADER,
+            [
+                "1",
+                "2",
+                "2025-11-03 23:59:59",
+                "Fyrryn",
+                "Fyrryn",
+                "ok",
+                "1.2ms",
+            ],
+            ["10", "3", "", "", "not_implemented", "", ""],
+        ]
+        .map(|row| row.map(String::from));
+        assert_eq!(
+            format_table(&rows),
+            "Quest  Part  Completed            Accepted  Answer           Check  Time\n\
+            \x20   1     2  2025-11-03 23:59:59  Fyrryn    Fyrryn           ok     1.2ms\n\
+            \x20  10     3                                 not_implemented\n"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("3"), Some("3")), "ok");
+        assert_eq!(check(Some("3"), Some("4")), "WRONG");
+        assert_eq!(check(None, Some("4")), "");
+        assert_eq!(check(Some("3"), None), "");
+    }
+}
@@ -0,0 +1,587 @@
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::Day;
+use crate::report::{PartReport, Status};
+use crate::runner::{Event, Runner, RunnerError, Sampling, parse_only};
+
+/// A puzzle example of a single part, along with its expected answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub part: u16,
+    pub input: &'static str,
+    /// Parameters differing from the defaults of the solution, as `(name, value)` pairs
+    pub params: &'static [(&'static str, &'static str)],
+    pub expected: &'static str,
+    /// Why the solution is known to get this example wrong
+    pub known_failure: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(part: u16, input: &'static str, expected: &'static str) -> Self {
+        Self {
+            part,
+            input,
+            params: &[],
+            expected,
+            known_failure: None,
+        }
+    }
+
+    pub const fn with_params(mut self, params: &'static [(&'static str, &'static str)]) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Marks the example as one the solution gets wrong. It then no longer counts as failed,
+    /// but instead does once it passes, so that the mark gets removed.
+    pub const fn known_failure(mut self, reason: &'static str) -> Self {
+        self.known_failure = Some(reason);
+        self
+    }
+}
+
+/// A registered solution, along with the metadata shown by the `list` subcommand.
+pub struct DayEntry {
+    pub day: u16,
+    pub title: &'static str,
+    /// Parts overriding the `todo!()` defaults of the `Day` trait, see [`Day::PARTS`]
+    pub parts: &'static [u16],
+    pub examples: &'static [Example],
+    run_input: fn(&Runner, u16, u16, String, Sampling) -> PartReport,
+    parse_only: fn(&str) -> Result<(), String>,
+}
+
+impl DayEntry {
+    pub const fn new<D: Day + 'static>(day: u16, title: &'static str) -> Self {
+        Self {
+            day,
+            title,
+            parts: D::PARTS,
+            examples: &[],
+            run_input: Runner::run_input::<D>,
+            parse_only: parse_only::<D>,
+        }
+    }
+
+    pub const fn with_examples(mut self, examples: &'static [Example]) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    pub fn is_implemented(&self, part: u16) -> bool {
+        self.parts.contains(&part)
+    }
+
+    /// The examples along with their number, counting those of each part separately from one.
+    pub fn numbered_examples(&self) -> impl Iterator<Item = (usize, &Example)> {
+        let mut numbers = [0; 4];
+        self.examples.iter().map(move |example| {
+            let number = &mut numbers[usize::from(example.part)];
+            *number += 1;
+            (*number, example)
+        })
+    }
+
+    /// Downloads the input if needed, reporting a locked or unavailable part rather than failing.
+    /// Only errors that would fail every other part as well are returned.
+    fn ensure_input(
+        &self,
+        runner: &mut Runner,
+        part: u16,
+    ) -> Result<Option<PartReport>, RunnerError> {
+        if !self.is_implemented(part) {
+            return Ok(None);
+        }
+        match runner.ensure_input(self.day, part) {
+            Ok(()) => Ok(None),
+            Err(RunnerError::LockedPart { .. }) => {
+                Ok(Some(PartReport::new(self.day, part, Status::Locked)))
+            }
+            Err(RunnerError::Offline) => Ok(Some(
+                PartReport::new(self.day, part, Status::MissingInput)
+                    .with_error(&"input not available offline"),
+            )),
+            Err(
+                err @ (RunnerError::MissingCookie
+                | RunnerError::ExpiredSession
+                | RunnerError::InvalidBaseUrl(_)),
+            ) => Err(err),
+            Err(err) => Ok(Some(
+                PartReport::new(self.day, part, Status::DownloadFailed).with_error(&err),
+            )),
+        }
+    }
+
+    pub fn run(
+        &self,
+        runner: &mut Runner,
+        part: u16,
+        sampling: Sampling,
+    ) -> Result<PartReport, RunnerError> {
+        if let Some(report) = self.ensure_input(runner, part)? {
+            return Ok(report);
+        }
+        Ok(self.run_downloaded(runner, part, sampling))
+    }
+
+    /// Runs a part on its input if downloaded, without downloading it otherwise.
+    pub fn run_downloaded(&self, runner: &Runner, part: u16, sampling: Sampling) -> PartReport {
+        if !self.is_implemented(part) {
+            return PartReport::new(self.day, part, Status::NotImplemented);
+        }
+        let Some(input_text) = runner.read_input(self.day, part) else {
+            return PartReport::new(self.day, part, Status::MissingInput);
+        };
+        (self.run_input)(runner, self.day, part, input_text, sampling)
+    }
+
+    /// Runs a part on the given input instead of the downloaded one.
+    pub fn run_on(
+        &self,
+        runner: &Runner,
+        part: u16,
+        input_text: String,
+        sampling: Sampling,
+    ) -> PartReport {
+        if !self.is_implemented(part) {
+            return PartReport::new(self.day, part, Status::NotImplemented);
+        }
+        (self.run_input)(runner, self.day, part, input_text, sampling)
+    }
+
+    /// Computes the answer for a single part.
+    pub fn solve(&self, runner: &mut Runner, part: u16) -> Result<Option<String>, RunnerError> {
+        Ok(self.run(runner, part, Sampling::repeat(1))?.answer)
+    }
+}
+
+/// Outcome of running the solutions on the examples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExampleSummary {
+    pub passed: usize,
+    pub failed: usize,
+    /// Examples marked as known failures, that still fail
+    pub known_failures: usize,
+    /// Examples of parts that are not implemented yet
+    pub skipped: usize,
+}
+
+/// Outcome of parsing the inputs and the examples.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CheckSummary {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Outcome of comparing the solutions against the known answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VerifySummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+pub struct Registry {
+    pub event: Event,
+    pub days: &'static [DayEntry],
+}
+
+impl Registry {
+    pub fn get(&self, day: u16) -> Option<&DayEntry> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+
+    pub fn print_list(&self) {
+        let title_width = self
+            .days
+            .iter()
+            .map(|entry| entry.title.len())
+            .max()
+            .unwrap_or(0);
+        println!("Event {}", self.event);
+        println!();
+        println!("Quest  {:title_width$}  Part 1  Part 2  Part 3", "Title");
+        for entry in self.days {
+            let parts = (1..=3)
+                .map(|part| {
+                    if entry.is_implemented(part) {
+                        "done"
+                    } else {
+                        "todo"
+                    }
+                })
+                .collect::<Vec<_>>();
+            println!(
+                "{:>5}  {:title_width$}  {}",
+                entry.day,
+                entry.title,
+                parts.join("    ")
+            );
+        }
+    }
+
+    /// Runs the selected parts on the rayon pool, returning the reports in day and part order.
+    pub fn run_parallel(
+        &self,
+        runner: &mut Runner,
+        day_filter: Option<u16>,
+        part_filter: Option<u16>,
+        sampling: Sampling,
+    ) -> Result<Vec<PartReport>, RunnerError> {
+        let selected = self
+            .days
+            .iter()
+            .filter(|entry| day_filter.is_none_or(|d| d == entry.day))
+            .flat_map(|entry| (1..=3).map(move |part| (entry, part)))
+            .filter(|&(_, part)| part_filter.is_none_or(|p| p == part))
+            .collect::<Vec<_>>();
+        // Download up front, so each quest is only fetched once.
+        let locked = selected
+            .iter()
+            .map(|&(entry, part)| entry.ensure_input(runner, part))
+            .collect::<Result<Vec<_>, _>>()?;
+        let runner = &*runner;
+        Ok(selected
+            .into_par_iter()
+            .zip(locked)
+            .map(|((entry, part), locked)| {
+                locked.unwrap_or_else(|| PartReport {
+                    parallel: true,
+                    ..entry.run_downloaded(runner, part, sampling)
+                })
+            })
+            .collect())
+    }
+
+    pub fn run_examples(
+        &self,
+        runner: &Runner,
+        day_filter: Option<u16>,
+        part_filter: Option<u16>,
+    ) -> ExampleSummary {
+        let mut summary = ExampleSummary::default();
+        for entry in self.days {
+            if day_filter.is_some_and(|d| d != entry.day) {
+                continue;
+            }
+            for (number, example) in entry.numbered_examples() {
+                let Example { part, expected, .. } = *example;
+                if part_filter.is_some_and(|p| p != part) {
+                    continue;
+                }
+                let day = entry.day;
+                let label = format!("Quest {day} - Part {part} - Example {number}");
+                if !entry.is_implemented(part) {
+                    summary.skipped += 1;
+                    println!("{label}: SKIPPED (not implemented)");
+                    continue;
+                }
+                let report = entry.run_on(
+                    &runner.with_params(example.params),
+                    part,
+                    example.input.to_string(),
+                    Sampling::repeat(1),
+                );
+                let outcome = match report.answer {
+                    Some(actual) if actual == expected => Ok(actual),
+                    Some(actual) => Err(format!("got {actual}, expected {expected}")),
+                    None => {
+                        let error = report
+                            .error
+                            .unwrap_or_else(|| report.status.as_str().into());
+                        Err(format!("{error}, expected {expected}"))
+                    }
+                };
+                match (outcome, example.known_failure) {
+                    (Ok(actual), None) => {
+                        summary.passed += 1;
+                        println!("{label}: PASS ({actual})");
+                    }
+                    (Ok(actual), Some(reason)) => {
+                        summary.failed += 1;
+                        println!("{label}: FAIL (passes with {actual}, but is marked: {reason})");
+                    }
+                    (Err(error), None) => {
+                        summary.failed += 1;
+                        println!("{label}: FAIL ({error})");
+                    }
+                    (Err(error), Some(reason)) => {
+                        summary.known_failures += 1;
+                        println!("{label}: KNOWN FAIL ({error}; {reason})");
+                    }
+                }
+            }
+        }
+        summary
+    }
+
+    /// Parses the downloaded inputs and the embedded examples, printing those that fail.
+    pub fn check(&self, runner: &Runner, day_filter: Option<u16>) -> CheckSummary {
+        let mut summary = CheckSummary::default();
+        let mut record = |label: &str, result: Result<(), String>| match result {
+            Ok(()) => summary.passed += 1,
+            Err(error) => {
+                summary.failed += 1;
+                println!("{label}: FAIL ({error})");
+            }
+        };
+        for entry in self.days {
+            if day_filter.is_some_and(|d| d != entry.day) {
+                continue;
+            }
+            let day = entry.day;
+            for part in 1..=3 {
+                if let Some(input_text) = runner.read_input(day, part) {
+                    let label = runner.input_path(day, part).display().to_string();
+                    record(&label, (entry.parse_only)(&input_text));
+                }
+            }
+            for (number, example) in entry.numbered_examples() {
+                let part = example.part;
+                let label = format!("Quest {day} - Part {part} - Example {number}");
+                record(&label, (entry.parse_only)(example.input));
+            }
+        }
+        summary
+    }
+
+    pub fn verify(
+        &self,
+        runner: &mut Runner,
+        day_filter: Option<u16>,
+        part_filter: Option<u16>,
+    ) -> Result<VerifySummary, RunnerError> {
+        let mut summary = VerifySummary::default();
+        let mut refresh = true;
+        for entry in self.days {
+            let day = entry.day;
+            if day_filter.is_some_and(|d| d != day) {
+                continue;
+            }
+            let parts = entry
+                .parts
+                .iter()
+                .copied()
+                .filter(|&part| part_filter.is_none_or(|p| p == part))
+                .collect::<Vec<_>>();
+            if refresh {
+                refresh = refresh_answers(runner, day, &parts);
+            }
+            for part in parts {
+                let actual = entry.solve(runner, part)?;
+                let expected = runner.known_answer(day, part);
+                match (actual, expected) {
+                    (Some(actual), Some(expected)) if actual == expected => {
+                        summary.passed += 1;
+                        println!("Quest {day} - Part {part}: PASS ({actual})");
+                    }
+                    (actual, Some(expected)) => {
+                        summary.failed += 1;
+                        let actual = actual.as_deref().unwrap_or("no answer");
+                        println!(
+                            "Quest {day} - Part {part}: FAIL (got {actual}, expected {expected})"
+                        );
+                    }
+                    (actual, None) => {
+                        summary.unknown += 1;
+                        let actual = actual.as_deref().unwrap_or("no answer");
+                        println!("Quest {day} - Part {part}: UNKNOWN ({actual})");
+                    }
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+/// Refreshes the known answers of the `parts`, see [`Runner::refresh_answers`]. The stored
+/// answers are used when that fails. Returns whether to keep refreshing, which is pointless after
+/// an error that would repeat for every quest.
+pub(crate) fn refresh_answers(runner: &mut Runner, day: u16, parts: &[u16]) -> bool {
+    match runner.refresh_answers(day, parts) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("Using the stored answers of quest {day}: {err}");
+            !matches!(
+                err,
+                RunnerError::Network(_)
+                    | RunnerError::MissingCookie
+                    | RunnerError::ExpiredSession
+                    | RunnerError::InvalidBaseUrl(_)
+            )
+        }
+    }
+}
+
+/// Checks the solution against each example, with the parameters of the example. Parts not
+/// listed in [`Day::PARTS`] are skipped, and known failures have to keep failing.
+#[cfg(test)]
+pub fn check_examples<D: Day + 'static>(examples: &'static [Example]) {
+    // Keep the caught panics of failing examples out of the test output.
+    crate::runner::install_panic_hook();
+    let entry = DayEntry::new::<D>(0, "").with_examples(examples);
+    for (number, example) in entry.numbered_examples() {
+        let part = example.part;
+        if !entry.is_implemented(part) {
+            continue;
+        }
+        let label = format!("Part {part} - Example {number}");
+        let runner = Runner::default().with_params(example.params);
+        let input = example.input.to_string();
+        let report = entry.run_on(&runner, part, input, Sampling::repeat(1));
+        let answer = report.answer.as_deref();
+        if let Some(reason) = example.known_failure {
+            assert_ne!(
+                answer,
+                Some(example.expected),
+                "{label} passes, but is marked as a known failure: {reason}"
+            );
+        } else {
+            assert_eq!(
+                answer,
+                Some(example.expected),
+                "{label}: {:?}",
+                report.error
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use super::*;
+    use crate::cli::HttpConfig;
+    use crate::crypto::tests::KEY;
+    use crate::day_03::Day03;
+    use crate::runner::tests::{UNREACHABLE_URL, serve, temp_input_root, test_runner};
+
+    const EXAMPLES: &[Example] = &[
+        Example::new(1, "10,5,1", "16"),
+        Example::new(3, "10,5,1\n", "1"),
+    ];
+
+    const REGISTRY: Registry = Registry {
+        event: Event::Main(2025),
+        days: &[DayEntry::new::<Day03>(3, "The Deepest Fit").with_examples(EXAMPLES)],
+    };
+
+    #[test]
+    fn test_check() {
+        let input_root = temp_input_root("registry-check");
+        let runner = Runner::new(Event::Main(2025), UNREACHABLE_URL, &input_root);
+        let event_dir = input_root.join("2025");
+        std::fs::create_dir_all(&event_dir).unwrap();
+        std::fs::write(event_dir.join("day_03_part_1.txt"), "10,5,1").unwrap();
+        std::fs::write(event_dir.join("day_03_part_2.txt"), "10,x,1").unwrap();
+
+        let summary = REGISTRY.check(&runner, None);
+        assert_eq!(
+            summary,
+            CheckSummary {
+                passed: 2,
+                failed: 2
+            }
+        );
+        assert_eq!(REGISTRY.check(&runner, Some(4)), CheckSummary::default());
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    /// Quest 3 with part 2 still on its `todo!()` default
+    struct Partial;
+
+    impl Day for Partial {
+        type Input = <Day03 as Day>::Input;
+        type ParseError = <Day03 as Day>::ParseError;
+        type Params = ();
+        const PARTS: &[u16] = &[1, 3];
+
+        fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+            Day03::parse(input)
+        }
+
+        fn part_1(input: &Self::Input) -> impl Display {
+            Day03::part_1(input)
+        }
+
+        fn part_3(input: &Self::Input) -> impl Display {
+            Day03::part_3(input)
+        }
+    }
+
+    #[test]
+    fn test_run_examples() {
+        const EXAMPLES: &[Example] = &[
+            Example::new(1, "10,5,1", "16"),
+            Example::new(1, "10,5,1", "17"),
+            Example::new(1, "10,5,1", "17").known_failure("wrong on purpose"),
+            Example::new(2, "10,5,1", "1"),
+            Example::new(3, "10,5,1", "1").known_failure("fixed since"),
+        ];
+        const REGISTRY: Registry = Registry {
+            event: Event::Main(2025),
+            days: &[DayEntry::new::<Partial>(3, "The Deepest Fit").with_examples(EXAMPLES)],
+        };
+        let summary = REGISTRY.run_examples(&Runner::default(), None, None);
+        assert_eq!(
+            summary,
+            ExampleSummary {
+                passed: 1,
+                failed: 2,
+                known_failures: 1,
+                skipped: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let (base_url, server) = serve(vec![(
+            200,
+            format!(r#"{{"key1":"{KEY}","answer1":"16","answer3":"2"}}"#),
+        )]);
+        let (mut runner, input_root) = test_runner("registry-verify", &base_url);
+        // Inputs downloaded before the answers were stored, in the old location
+        for part in 1..=3 {
+            std::fs::write(input_root.join(format!("day_03_part_{part}.txt")), "10,5,1").unwrap();
+        }
+
+        let summary = REGISTRY.verify(&mut runner, None, None).unwrap();
+        assert_eq!(
+            summary,
+            VerifySummary {
+                passed: 1,
+                failed: 1,
+                unknown: 1,
+            }
+        );
+        assert_eq!(runner.known_answer(3, 1).as_deref(), Some("16"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /api/event/2025/quest/3 "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_download_failed() {
+        let (mut runner, input_root) = test_runner("registry-download", UNREACHABLE_URL);
+        runner.set_http_config(HttpConfig {
+            retries: 0,
+            ..HttpConfig::default()
+        });
+
+        let reports = (1..=2)
+            .map(|part| REGISTRY.days[0].run(&mut runner, part, Sampling::repeat(1)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(
+            reports
+                .iter()
+                .all(|report| report.status == Status::DownloadFailed && report.error.is_some())
+        );
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+}
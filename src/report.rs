@@ -0,0 +1,297 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::memory::MemStats;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    ParseError,
+    /// The solver panicked
+    Failed,
+    MissingInput,
+    NotImplemented,
+    Timeout,
+    /// The previous part has not been solved yet
+    Locked,
+    /// The input could not be downloaded
+    DownloadFailed,
+    /// A `--param` override is unknown to the quest, or has an invalid value
+    InvalidParams,
+}
+
+impl Status {
+    /// Whether the part counts as failed in the exit code.
+    pub const fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Self::ParseError
+                | Self::Failed
+                | Self::Timeout
+                | Self::DownloadFailed
+                | Self::InvalidParams
+        )
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::ParseError => "parse_error",
+            Self::Failed => "failed",
+            Self::MissingInput => "missing_input",
+            Self::NotImplemented => "not_implemented",
+            Self::Timeout => "timeout",
+            Self::Locked => "locked",
+            Self::DownloadFailed => "download_failed",
+            Self::InvalidParams => "invalid_params",
+        }
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Outcome of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub day: u16,
+    pub part: u16,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Parse error, panic message, exceeded time limit, or why the input is missing
+    pub error: Option<String>,
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse_time: Duration,
+    /// Average time of a single sample
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub solve_time: Duration,
+    pub samples: u32,
+    /// Whether the part ran alongside others, so the timings are under contention
+    pub parallel: bool,
+    /// Allocations while parsing, when tracked with `--mem`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mem: Option<MemStats>,
+    /// Allocations of the first sample, when tracked with `--mem`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_mem: Option<MemStats>,
+    #[serde(skip)]
+    pub sample_times: Vec<Duration>,
+}
+
+impl PartReport {
+    pub const fn new(day: u16, part: u16, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            status,
+            answer: None,
+            error: None,
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            samples: 0,
+            parallel: false,
+            parse_mem: None,
+            solve_mem: None,
+            sample_times: Vec::new(),
+        }
+    }
+
+    /// Marks the timings as measured under contention, when `contended` is set.
+    pub const fn contended(mut self, contended: bool) -> Self {
+        self.parallel |= contended;
+        self
+    }
+
+    pub fn with_sample_times(mut self, sample_times: Vec<Duration>) -> Self {
+        let samples = u32::try_from(sample_times.len()).expect("Sample count fits in u32");
+        if samples > 0 {
+            self.solve_time = sample_times.iter().sum::<Duration>() / samples;
+        }
+        self.samples = samples;
+        self.sample_times = sample_times;
+        self
+    }
+
+    pub fn with_error(mut self, error: &impl ToString) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+}
+
+/// Prints part reports in the chosen format, one record per part.
+#[derive(Debug, Clone)]
+pub struct Reporter {
+    format: Format,
+    /// Whether to add the allocation columns to the CSV output
+    mem: bool,
+    last_day: Option<u16>,
+}
+
+impl Reporter {
+    pub fn new(format: Format, mem: bool) -> Self {
+        if format == Format::Csv {
+            print!("day,part,status,answer,error,parse_ns,solve_ns,samples,parallel");
+            if mem {
+                print!(",parse_allocations,parse_bytes,parse_peak_bytes");
+                print!(",solve_allocations,solve_bytes,solve_peak_bytes");
+            }
+            println!();
+        }
+        Self {
+            format,
+            mem,
+            last_day: None,
+        }
+    }
+
+    pub fn print(&mut self, report: &PartReport) {
+        match self.format {
+            Format::Text => self.print_text(report),
+            Format::Json => {
+                println!(
+                    "{}",
+                    serde_json::to_string(report).expect("Serialize report")
+                );
+            }
+            Format::Csv => {
+                print!(
+                    "{},{},{},{},{},{},{},{},{}",
+                    report.day,
+                    report.part,
+                    report.status.as_str(),
+                    csv_field(report.answer.as_deref()),
+                    csv_field(report.error.as_deref()),
+                    report.parse_time.as_nanos(),
+                    report.solve_time.as_nanos(),
+                    report.samples,
+                    report.parallel
+                );
+                if self.mem {
+                    for stats in [report.parse_mem, report.solve_mem] {
+                        let stats = stats.unwrap_or_default();
+                        print!(
+                            ",{},{},{}",
+                            stats.allocations, stats.bytes, stats.peak_bytes
+                        );
+                    }
+                }
+                println!();
+            }
+        }
+    }
+
+    fn print_text(&mut self, report: &PartReport) {
+        let PartReport { day, part, .. } = *report;
+        if self.last_day != Some(day) {
+            println!();
+            self.last_day = Some(day);
+        }
+        match report.status {
+            Status::Ok => {
+                let answer = report.answer.as_deref().unwrap_or_default();
+                println!("Quest {day} - Part {part}: {answer}");
+                print!("          parsing: {:?}", report.parse_time);
+                match report.parse_mem {
+                    Some(stats) => println!(" ({stats})"),
+                    None => println!(),
+                }
+                print!("          runner: {:?}", report.solve_time);
+                let mut notes = Vec::new();
+                if report.samples > 1 {
+                    notes.push(format!("{} samples", report.samples));
+                }
+                if report.parallel {
+                    notes.push("measured in parallel".to_string());
+                }
+                if let Some(stats) = report.solve_mem {
+                    notes.push(stats.to_string());
+                }
+                if notes.is_empty() {
+                    println!();
+                } else {
+                    println!(" ({})", notes.join(", "));
+                }
+                println!();
+            }
+            Status::ParseError => {
+                let error = report.error.as_deref().unwrap_or_default();
+                println!("Parse error: {error}");
+            }
+            Status::Failed => {
+                let error = report.error.as_deref().unwrap_or_default();
+                println!("Quest {day} - Part {part}: FAILED: {error}");
+            }
+            Status::MissingInput => match &report.error {
+                Some(error) => println!("Quest {day} - Part {part}: missing input ({error})"),
+                None => println!("Quest {day} - Part {part}: missing input"),
+            },
+            Status::NotImplemented => println!("Quest {day} - Part {part}: not implemented"),
+            // The keys are cached, so a part solved on the site since then still shows as locked.
+            Status::Locked => println!(
+                "Quest {day} - Part {part}: part locked (solved on the site? run `download -d {day}` to refresh)"
+            ),
+            Status::Timeout => {
+                let error = report.error.as_deref().unwrap_or_default();
+                println!("Quest {day} - Part {part}: TIMEOUT after {error}");
+            }
+            Status::DownloadFailed => {
+                let error = report.error.as_deref().unwrap_or_default();
+                println!("Quest {day} - Part {part}: DOWNLOAD FAILED: {error}");
+            }
+            Status::InvalidParams => {
+                let error = report.error.as_deref().unwrap_or_default();
+                println!("Quest {day} - Part {part}: invalid parameter: {error}");
+            }
+        }
+    }
+}
+
+fn csv_field(value: Option<&str>) -> String {
+    match value {
+        Some(value) if value.contains([',', '"', '\n', '\r']) => {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }
+        Some(value) => value.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field(None), "");
+        assert_eq!(csv_field(Some("Fyrryn")), "Fyrryn");
+        assert_eq!(csv_field(Some("[357,862]")), "\"[357,862]\"");
+        assert_eq!(csv_field(Some("say \"hi\"")), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_json_record() {
+        let report = PartReport {
+            answer: Some("29".to_string()),
+            parse_time: Duration::from_micros(3),
+            solve_time: Duration::from_nanos(1500),
+            samples: 2,
+            ..PartReport::new(3, 1, Status::Ok)
+        };
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":3,"part":1,"status":"ok","answer":"29","error":null,"parse_ns":3000,"solve_ns":1500,"samples":2,"parallel":false}"#
+        );
+    }
+}
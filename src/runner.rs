@@ -1,55 +1,166 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt::Display;
 use std::hint::black_box;
-use std::sync::Arc;
-use std::time::{Duration, Instant};
-
-use aes::Aes256;
-use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
-use cbc::Decryptor;
-use clap::Parser;
-use clap_derive::Subcommand;
-use serde::Deserialize;
+use std::io::Write;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Once, mpsc};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use ureq::config::Config;
-use ureq::http::Uri;
-use ureq::{Agent, Cookie};
+use ureq::http::{Response, Uri};
+use ureq::{Agent, Body, Cookie, Proxy};
 
 use crate::Day;
+use crate::cli::HttpConfig;
+use crate::crypto::{DecryptError, InputData, decrypt_part};
+use crate::description::Description;
+use crate::memory::{self, MemStats};
+use crate::params::Params;
+use crate::progress::PartProgress;
+use crate::report::{PartReport, Status};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// The yearly main event, numbered by year
+    Main(u16),
+    /// One of the shorter stories, numbered from 1
+    Story(u16),
+}
+
+impl Event {
+    /// The number identifying the event in API and asset URLs.
+    const fn id(self) -> u16 {
+        match self {
+            Self::Main(year) => year,
+            Self::Story(story) => story,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EventError {
+    #[error("Unknown event {0:?}, expected a year such as 2025 or a story such as story-1")]
+    Unknown(String),
+}
 
-#[derive(Parser)]
-pub struct Cli {
-    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
-    pub day: Option<u16>,
-    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=3))]
-    pub part: Option<u16>,
-    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
-    pub repeat: Option<u32>,
-    #[command(subcommand)]
-    pub command: Option<Command>,
-}
-
-#[derive(Subcommand)]
-pub enum Command {
-    Download {
-        #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..=25))]
+impl FromStr for Event {
+    type Err = EventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix("story")
+            .map_or_else(
+                || s.parse().ok().filter(|&year| year >= 2024).map(Self::Main),
+                |story| {
+                    let story = story.strip_prefix('-').unwrap_or(story);
+                    story
+                        .parse()
+                        .ok()
+                        .filter(|&story| story >= 1)
+                        .map(Self::Story)
+                },
+            )
+            .ok_or_else(|| EventError::Unknown(s.to_string()))
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Main(year) => write!(f, "{year}"),
+            Self::Story(story) => write!(f, "story-{story}"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum RunnerError {
+    #[error("Network error: {0}")]
+    Network(ureq::Error),
+    #[error("The server responded with HTTP status {0}")]
+    HttpStatus(u16),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Could not decrypt quest {day} part {part}: {source}")]
+    Decryption {
         day: u16,
+        part: u16,
+        #[source]
+        source: DecryptError,
     },
-    Cookie {
-        cookie: String,
+    #[error("{}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
     },
+    #[error("Cookie not found. Set {COOKIE_VAR}, or use the `cookie` subcommand to save it")]
+    MissingCookie,
+    #[error(
+        "The session has expired. Log in to everybody.codes again, and use the `cookie` \
+        subcommand to save the new cookie"
+    )]
+    ExpiredSession,
+    #[error("Quest {day} part {part} is locked")]
+    LockedPart { day: u16, part: u16 },
+    #[error("Not available offline")]
+    Offline,
+    #[error("Invalid base URL {0:?}")]
+    InvalidBaseUrl(String),
 }
 
-#[derive(Debug, Clone, Deserialize)]
-struct UserInfo {
-    seed: u16,
+impl RunnerError {
+    /// Process exit code for the category of the error. These stay clear of the exit codes of
+    /// failed parts used by a normal run, which end at 99, and of the 101 of a panic.
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Network(_) => 110,
+            Self::HttpStatus(_) => 111,
+            Self::Json(_) => 112,
+            Self::Decryption { .. } => 113,
+            Self::Io { .. } => 114,
+            Self::MissingCookie => 115,
+            Self::LockedPart { .. } => 116,
+            Self::ExpiredSession => 117,
+            Self::Offline => 118,
+            Self::InvalidBaseUrl(_) => 119,
+        }
+    }
+
+    pub fn io(path: &Path) -> impl FnOnce(std::io::Error) -> Self {
+        move |source| Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
 }
 
+impl From<ureq::Error> for RunnerError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::StatusCode(status) => Self::HttpStatus(status),
+            ureq::Error::Json(err) => Self::Json(err),
+            err => Self::Network(err),
+        }
+    }
+}
+
+/// The logged in user, as returned by `/api/user/me`.
 #[derive(Debug, Clone, Deserialize)]
-struct InputData {
-    #[serde(rename = "1", with = "hex::serde")]
-    first: Vec<u8>, // TODO: Make optional
-    #[serde(rename = "2", with = "hex::serde")]
-    second: Vec<u8>,
-    #[serde(rename = "3", with = "hex::serde")]
-    third: Vec<u8>,
+pub struct UserInfo {
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Zero when the session is not logged in
+    #[serde(default)]
+    pub seed: u16,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -57,181 +168,1728 @@ struct Keys {
     key1: Option<String>,
     key2: Option<String>,
     key3: Option<String>,
-    // answer1: Option<String>,
-    // answer2: Option<String>,
-    // answer3: Option<String>,
+    answer1: Option<String>,
+    answer2: Option<String>,
+    answer3: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct AnswerRequest<'a> {
+    answer: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AnswerResponse {
+    correct: bool,
+    #[serde(default)]
+    length_correct: bool,
+    #[serde(default)]
+    first_correct: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feedback {
+    Correct,
+    Wrong {
+        length_correct: bool,
+        first_correct: bool,
+    },
+}
+
+impl From<AnswerResponse> for Feedback {
+    fn from(response: AnswerResponse) -> Self {
+        if response.correct {
+            Self::Correct
+        } else {
+            Self::Wrong {
+                length_correct: response.length_correct,
+                first_correct: response.first_correct,
+            }
+        }
+    }
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong {
+                length_correct,
+                first_correct,
+            } => {
+                write!(f, "wrong")?;
+                match (length_correct, first_correct) {
+                    (true, true) => write!(f, " (length and first character correct)"),
+                    (true, false) => write!(f, " (length correct)"),
+                    (false, true) => write!(f, " (first character correct)"),
+                    (false, false) => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    day: u16,
+    part: u16,
+    answer: String,
+    feedback: Feedback,
+    /// Seconds since the unix epoch
+    timestamp: u64,
+}
+
+/// Local record of every submitted answer, used to avoid resending rejected answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionLog {
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+    /// Loads the log, which is empty until the first submission. Any other error is returned, so
+    /// that the next save does not overwrite the rejected answers.
+    fn load(filename: &Path) -> Result<Self, RunnerError> {
+        match std::fs::read_to_string(filename) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(RunnerError::io(filename)(err)),
+        }
+    }
+
+    fn save(&self, filename: &Path) -> Result<(), RunnerError> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(filename, json).map_err(RunnerError::io(filename))
+    }
+
+    /// The previous attempt that makes submitting `answer` pointless, if any.
+    fn blocking_attempt(&self, day: u16, part: u16, answer: &str) -> Option<&Attempt> {
+        let mut attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part);
+        attempts
+            .clone()
+            .find(|attempt| attempt.feedback == Feedback::Correct)
+            .or_else(|| attempts.find(|attempt| attempt.answer == answer))
+    }
+
+    /// When an answer to the part was accepted, in seconds since the unix epoch.
+    fn accepted_at(&self, day: u16, part: u16) -> Option<u64> {
+        self.attempts
+            .iter()
+            .find(|attempt| {
+                attempt.day == day && attempt.part == part && attempt.feedback == Feedback::Correct
+            })
+            .map(|attempt| attempt.timestamp)
+    }
+
+    fn record(&mut self, day: u16, part: u16, answer: &str, feedback: Feedback) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            feedback,
+            timestamp,
+        });
+    }
+}
+
+fn post_answer(cli: &Client, url: &str, answer: &str) -> Result<Feedback, RunnerError> {
+    let response = cli
+        .post_json(url, &AnswerRequest { answer })?
+        .body_mut()
+        .read_json::<AnswerResponse>()?;
+    Ok(response.into())
+}
+
+/// An HTTP agent that retries failed requests as set up by the [`HttpConfig`].
+struct Client {
+    agent: Agent,
+    config: HttpConfig,
+}
+
+impl Client {
+    fn new(config: &HttpConfig) -> Result<Self, RunnerError> {
+        let mut builder = Agent::config_builder()
+            .timeout_global(Some(config.timeout))
+            .user_agent(APP_USER_AGENT)
+            // Keep the response, to read the `Retry-After` header
+            .http_status_as_error(false);
+        // Without a proxy given, ureq picks one from the environment.
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(Some(Proxy::new(proxy)?));
+        }
+        let agent_config: Config = builder.build();
+        Ok(Self {
+            agent: agent_config.into(),
+            config: config.clone(),
+        })
+    }
+
+    fn get(&self, url: &str) -> Result<Response<Body>, RunnerError> {
+        self.send(true, || self.agent.get(url).call())
+    }
+
+    /// Posts a JSON document. Only retried on a 429 or 503, where the server did not handle the
+    /// request. After a network error or another server error it might have anyway.
+    fn post_json(&self, url: &str, data: &impl Serialize) -> Result<Response<Body>, RunnerError> {
+        self.send(false, || self.agent.post(url).send_json(data))
+    }
+
+    /// Sends a request, retrying it when it failed in a way that may go away. Requests that
+    /// are not `idempotent` are only retried when the server is known to have rejected them.
+    /// A `Retry-After` longer than the timeout times the number of retries fails right away.
+    fn send(
+        &self,
+        idempotent: bool,
+        request: impl Fn() -> Result<Response<Body>, ureq::Error>,
+    ) -> Result<Response<Body>, RunnerError> {
+        let mut retries = self.config.retries;
+        let mut backoff = self.config.backoff;
+        let max_delay = self.config.timeout * self.config.retries;
+        loop {
+            let delay = match request() {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let retryable = match status {
+                        429 | 503 => true,
+                        500 | 502 | 504 => idempotent,
+                        _ => false,
+                    };
+                    if retries == 0 || !retryable {
+                        return Err(RunnerError::HttpStatus(status));
+                    }
+                    match retry_after(&response) {
+                        Some(delay) if delay > max_delay => {
+                            return Err(RunnerError::HttpStatus(status));
+                        }
+                        Some(delay) => delay,
+                        None => backoff,
+                    }
+                }
+                Err(err) if retries > 0 && idempotent && is_transient(&err) => backoff,
+                Err(err) => return Err(err.into()),
+            };
+            eprintln!("Request failed, retrying in {delay:?}");
+            thread::sleep(delay);
+            retries -= 1;
+            backoff *= 2;
+        }
+    }
+}
+
+/// The delay asked for by a 429 or 503 response.
+fn retry_after(response: &Response<Body>) -> Option<Duration> {
+    if !matches!(response.status().as_u16(), 429 | 503) {
+        return None;
+    }
+    let value = response.headers().get("retry-after")?.to_str().ok()?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A `Retry-After` value, either in seconds or as an HTTP date such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`. A date in the past asks for no delay.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let [_, day, month, year, time, "GMT"] = value.split_whitespace().collect::<Vec<_>>()[..]
+    else {
+        return None;
+    };
+    let month = MONTHS.iter().position(|&name| name == month)? as u64 + 1;
+    let (day, year) = (day.parse::<u64>().ok()?, year.parse::<u64>().ok()?);
+    let mut hms = time.split(':').map(|field| field.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (hms.next()??, hms.next()??, hms.next()??);
+    // Days from civil, shifted so that years start in March, and leap days come last.
+    let year = year - u64::from(month <= 2);
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+    let date =
+        UNIX_EPOCH + Duration::from_secs(days * 86_400 + hours * 3600 + minutes * 60 + seconds);
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Whether the error is likely to go away by trying again.
+const fn is_transient(err: &ureq::Error) -> bool {
+    matches!(
+        err,
+        ureq::Error::Io(_)
+            | ureq::Error::Timeout(_)
+            | ureq::Error::HostNotFound
+            | ureq::Error::ConnectionFailed
+            | ureq::Error::Protocol(_)
+            | ureq::Error::BodyStalled
+    )
+}
+
+/// A puzzle example saved as a file, along with its answer when known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleFixture {
+    pub part: u16,
+    /// Counting the examples of each part separately, from one
+    pub number: usize,
+    pub input: String,
+    pub expected: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct Runner {
     cookie: Option<Arc<str>>,
+    cookie_file: Option<PathBuf>,
     seed: Option<u16>,
+    event: Event,
+    base_url: String,
+    input_root: PathBuf,
+    /// Time limit for parsing and solving a single part
+    timeout: Option<Duration>,
+    /// Solvers of timed out parts that are still running in the background
+    abandoned: Arc<AtomicUsize>,
+    /// Fail any request instead of sending it
+    offline: bool,
+    http: HttpConfig,
+    /// Overrides of the quest parameters, as `(name, value)` pairs
+    params: Vec<(String, String)>,
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new(Event::Main(2025), DEFAULT_BASE_URL, "./input")
+    }
 }
 
 const APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
+pub const DEFAULT_BASE_URL: &str = "https://everybody.codes/";
+
+/// Environment variable overriding the saved cookie.
+pub const COOKIE_VAR: &str = "EVERYBODY_CODES_COOKIE";
+
+/// `$XDG_CONFIG_HOME`, falling back to `~/.config`.
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+/// The contents of the first existing file.
+fn read_cookie_file(candidates: &[PathBuf]) -> Result<Option<String>, RunnerError> {
+    for filename in candidates {
+        match std::fs::read_to_string(filename) {
+            Ok(cookie_value) => return Ok(Some(cookie_value)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(RunnerError::io(filename)(err)),
+        }
+    }
+    Ok(None)
+}
+
+/// Writes a file only the user can read, such as the session cookie.
+fn write_private(filename: &Path, contents: &str) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(filename)?;
+    // The mode only applies to new files, not to those saved by older versions.
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(contents.as_bytes())
+}
+
 impl Runner {
-    pub fn save_cookie(&mut self, new_cookie: &str) {
-        let cookie_fn = "./input/cookie.txt";
-        std::fs::write(cookie_fn, new_cookie).expect("Write cookie file");
+    pub fn new(event: Event, base_url: &str, input_root: impl Into<PathBuf>) -> Self {
+        let mut base_url = base_url.to_string();
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        Self {
+            cookie: None,
+            cookie_file: None,
+            seed: None,
+            event,
+            base_url,
+            input_root: input_root.into(),
+            timeout: None,
+            abandoned: Arc::default(),
+            offline: false,
+            http: HttpConfig::default(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn set_cookie_file(&mut self, cookie_file: Option<PathBuf>) {
+        self.cookie_file = cookie_file;
+    }
+
+    pub const fn set_timeout(&mut self, timeout: Option<Duration>) {
+        self.timeout = timeout;
+    }
+
+    pub fn set_http_config(&mut self, http: HttpConfig) {
+        self.http = http;
+    }
+
+    pub const fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    pub fn set_params(&mut self, params: Vec<(String, String)>) {
+        self.params = params;
+    }
+
+    /// A copy of the runner, with the given parameters instead of the current ones.
+    pub fn with_params(&self, params: &[(&str, &str)]) -> Self {
+        let mut runner = self.clone();
+        runner.set_params(
+            params
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        );
+        runner
+    }
+
+    pub const fn event(&self) -> Event {
+        self.event
+    }
+
+    /// Directory holding the inputs and submissions of the current event.
+    fn event_dir(&self) -> PathBuf {
+        self.input_root.join(self.event.to_string())
+    }
+
+    /// Falls back to the input directory itself, where older versions kept the 2025 inputs.
+    pub fn input_path(&self, day: u16, part: u16) -> PathBuf {
+        let name = format!("day_{day:02}_part_{part}.txt");
+        let filename = self.event_dir().join(&name);
+        if self.event == Event::Main(2025) && !filename.exists() {
+            let legacy = self.input_root.join(&name);
+            if legacy.exists() {
+                return legacy;
+            }
+        }
+        filename
+    }
+
+    /// Raw server response cached for a day, such as the `keys` or the encrypted `input`.
+    fn cache_path(&self, day: u16, name: &str) -> PathBuf {
+        self.event_dir().join(format!("day_{day:02}_{name}.json"))
+    }
+
+    fn answer_path(&self, day: u16, part: u16) -> PathBuf {
+        self.event_dir()
+            .join(format!("day_{day:02}_part_{part}_answer.txt"))
+    }
+
+    fn example_path(&self, day: u16, part: u16, number: usize) -> PathBuf {
+        self.event_dir()
+            .join(format!("day_{day:02}_part_{part}_example_{number}.txt"))
+    }
+
+    fn example_answer_path(&self, day: u16, part: u16, number: usize) -> PathBuf {
+        self.event_dir().join(format!(
+            "day_{day:02}_part_{part}_example_{number}_answer.txt"
+        ))
+    }
+
+    /// Removes the saved examples of a part, so that none are left over from an earlier
+    /// description with more examples.
+    fn remove_examples(&self, day: u16, part: u16) -> Result<(), RunnerError> {
+        for number in 1.. {
+            let filenames = [
+                self.example_path(day, part, number),
+                self.example_answer_path(day, part, number),
+            ];
+            if !filenames.iter().any(|filename| filename.exists()) {
+                break;
+            }
+            for filename in filenames {
+                match std::fs::remove_file(&filename) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(RunnerError::io(&filename)(err)),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The examples saved next to the inputs, numbered from one for each part.
+    pub fn read_examples(&self, day: u16) -> Vec<ExampleFixture> {
+        let mut examples = Vec::new();
+        for part in 1..=3 {
+            for number in 1.. {
+                let Ok(input) = std::fs::read_to_string(self.example_path(day, part, number))
+                else {
+                    break;
+                };
+                let expected = std::fs::read_to_string(self.example_answer_path(day, part, number))
+                    .ok()
+                    .map(|answer| answer.trim().to_string());
+                examples.push(ExampleFixture {
+                    part,
+                    number,
+                    input: input.trim_end().to_string(),
+                    expected,
+                });
+            }
+        }
+        examples
+    }
+
+    /// Modification times and sizes of the inputs, example fixtures and answers of a day,
+    /// including the 2025 inputs still in their old location. See [`Self::input_path`].
+    pub fn day_files(&self, day: u16) -> BTreeMap<PathBuf, (SystemTime, u64)> {
+        let prefix = format!("day_{day:02}_part_");
+        let mut dirs = vec![self.event_dir()];
+        if self.event == Event::Main(2025) {
+            dirs.push(self.input_root.clone());
+        }
+        dirs.into_iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                name.starts_with(&prefix) && name.ends_with(".txt")
+            })
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                Some((entry.path(), (metadata.modified().ok()?, metadata.len())))
+            })
+            .collect()
+    }
+
+    /// The accepted answer for a part, as stored by `download` or `submit`.
+    pub fn known_answer(&self, day: u16, part: u16) -> Option<String> {
+        let answer = std::fs::read_to_string(self.answer_path(day, part)).ok()?;
+        Some(answer.trim().to_string())
+    }
+
+    fn save_answer(&self, day: u16, part: u16, answer: &str) -> Result<(), RunnerError> {
+        self.create_event_dir()?;
+        let filename = self.answer_path(day, part);
+        std::fs::write(&filename, answer).map_err(RunnerError::io(&filename))?;
+        eprintln!("Saved {}", filename.display());
+        Ok(())
+    }
+
+    fn create_event_dir(&self) -> Result<(), RunnerError> {
+        let event_dir = self.event_dir();
+        std::fs::create_dir_all(&event_dir).map_err(RunnerError::io(&event_dir))
+    }
+
+    /// Where `save_cookie` stores the cookie: the `--cookie-file`, or the XDG config directory.
+    fn cookie_file(&self) -> PathBuf {
+        self.cookie_file.clone().unwrap_or_else(|| {
+            config_dir().map_or_else(
+                || self.input_root.join("cookie.txt"),
+                |dir| dir.join("everybody-codes").join("cookie.txt"),
+            )
+        })
+    }
+
+    pub fn save_cookie(&mut self, new_cookie: &str) -> Result<(), RunnerError> {
+        let new_cookie = new_cookie.trim();
+        let cookie_fn = self.cookie_file();
+        if let Some(dir) = cookie_fn.parent() {
+            std::fs::create_dir_all(dir).map_err(RunnerError::io(dir))?;
+        }
+        write_private(&cookie_fn, new_cookie).map_err(RunnerError::io(&cookie_fn))?;
+        eprintln!("Saved {}", cookie_fn.display());
         self.cookie = Some(Arc::from(format!("everybody-codes={new_cookie}").as_str()));
+        Ok(())
     }
-    fn get_cookie(&mut self) -> Arc<str> {
-        if let Some(cookie) = &self.cookie {
-            return cookie.clone();
+    /// Looks up the cookie in the `--cookie-file`, then `EVERYBODY_CODES_COOKIE`, then the default
+    /// cookie file, and finally the `cookie.txt` in the input directory used by older versions.
+    fn read_cookie(&self, env_cookie: Option<String>) -> Result<String, RunnerError> {
+        if let Some(cookie_value) = read_cookie_file(self.cookie_file.as_slice())? {
+            return Ok(cookie_value);
         }
-        let cookie_fn = "./input/cookie.txt";
-        if std::fs::exists(cookie_fn).unwrap() {
-            let cookie_value = std::fs::read_to_string(cookie_fn).unwrap();
-            self.cookie = Some(Arc::from(
-                format!("everybody-codes={cookie_value}").as_str(),
-            ));
-            return self.cookie.as_ref().unwrap().clone();
+        if let Some(cookie_value) = env_cookie.filter(|value| !value.trim().is_empty()) {
+            return Ok(cookie_value);
         }
-        panic!("Cookie not found. Please use the `cookie` subcommand to set it");
+        if self.cookie_file.is_some() {
+            return Err(RunnerError::MissingCookie);
+        }
+        let candidates = [self.cookie_file(), self.input_root.join("cookie.txt")];
+        read_cookie_file(&candidates)?.ok_or(RunnerError::MissingCookie)
     }
-    fn cli_with_cookie(&mut self) -> Agent {
-        let config: Config = Agent::config_builder()
-            .timeout_global(Some(Duration::from_secs(5)))
-            .user_agent(APP_USER_AGENT)
-            .build();
-        let agent: Agent = config.into();
-        let uri = Uri::from_static("https://everybody.codes/");
+    fn get_cookie(&mut self) -> Result<Arc<str>, RunnerError> {
+        if let Some(cookie) = &self.cookie {
+            return Ok(cookie.clone());
+        }
+        let cookie_value = self.read_cookie(std::env::var(COOKIE_VAR).ok())?;
+        let cookie_value = cookie_value.trim();
+        let cookie: Arc<str> = Arc::from(format!("everybody-codes={cookie_value}").as_str());
+        self.cookie = Some(cookie.clone());
+        Ok(cookie)
+    }
+    fn cli_with_cookie(&mut self) -> Result<Client, RunnerError> {
+        if self.offline {
+            return Err(RunnerError::Offline);
+        }
+        let cli = Client::new(&self.http)?;
+        let uri = Uri::try_from(&self.base_url)
+            .map_err(|_| RunnerError::InvalidBaseUrl(self.base_url.clone()))?;
 
-        let cookie = self.get_cookie().to_string();
-        agent
+        let cookie = self.get_cookie()?.to_string();
+        cli.agent
             .cookie_jar_lock()
-            .insert(Cookie::parse(cookie, &uri).unwrap(), &uri)
-            .expect("Insert cookie");
+            .insert(Cookie::parse(cookie, &uri)?, &uri)?;
 
-        agent
+        Ok(cli)
     }
-    fn get_seed(&mut self) {
-        let cli = self.cli_with_cookie();
+    /// Fetches the logged in user, checking that the session is still valid.
+    pub fn whoami(&mut self) -> Result<UserInfo, RunnerError> {
+        let cli = self.cli_with_cookie()?;
 
         let user_info = cli
-            .get("https://everybody.codes/api/user/me")
-            .call()
-            .expect("request failed")
+            .get(&format!("{}api/user/me", self.base_url))
+            .map_err(|err| match err {
+                RunnerError::HttpStatus(401 | 403) => RunnerError::ExpiredSession,
+                err => err,
+            })?
             .body_mut()
-            .read_json::<UserInfo>()
-            .expect("json");
+            .read_json::<UserInfo>()?;
+        if user_info.seed == 0 {
+            return Err(RunnerError::ExpiredSession);
+        }
 
         self.seed = Some(user_info.seed);
+        Ok(user_info)
+    }
+    fn get_seed(&mut self) -> Result<u16, RunnerError> {
+        if let Some(seed) = self.seed {
+            return Ok(seed);
+        }
+        Ok(self.whoami()?.seed)
+    }
+    /// Downloads the quest, refreshing the keys. Parts decrypted before are kept as is.
+    pub fn download(&mut self, day: u16) -> Result<(), RunnerError> {
+        self.fetch_quest(day, true)
     }
-    pub fn download(&mut self, day: u16) {
-        let cli = self.cli_with_cookie();
 
-        let keys = cli
-            .get(format!(
-                "https://everybody.codes/api/event/2025/quest/{day}"
-            ))
-            .call()
-            .expect("request failed")
-            .body_mut()
-            .read_json::<Keys>()
-            .expect("Request failed");
+    /// Decrypts the parts that are unlocked but not decrypted yet. The encrypted input is only
+    /// fetched once, and the keys only when `refresh_keys` is set or none are cached.
+    fn fetch_quest(&mut self, day: u16, refresh_keys: bool) -> Result<(), RunnerError> {
+        let keys = self.keys(day, refresh_keys)?;
+        let pending = [&keys.key1, &keys.key2, &keys.key3]
+            .into_iter()
+            .zip(1..)
+            .filter_map(|(key, part)| Some((part, key.as_deref()?)))
+            .filter(|&(part, _)| !self.input_path(day, part).exists())
+            .collect::<Vec<_>>();
+        if refresh_keys {
+            for (key, part) in [&keys.key1, &keys.key2, &keys.key3].into_iter().zip(1..) {
+                if key.is_none() {
+                    eprintln!("No key for part {part}. Skipping.");
+                }
+            }
+        }
+        if pending.is_empty() {
+            return Ok(());
+        }
 
-        if self.seed.is_none() {
-            self.get_seed();
+        let parts = pending.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+        let input = self.cached_parts(day, "input", &parts)?;
+
+        for (part, key) in pending {
+            let Some(contents) = input.part(part) else {
+                eprintln!("No input for part {part}. Skipping.");
+                continue;
+            };
+            let decrypted = decrypt_part(contents, key)
+                .map_err(|source| RunnerError::Decryption { day, part, source })?;
+            let filename = self.input_path(day, part);
+            std::fs::write(&filename, decrypted).map_err(RunnerError::io(&filename))?;
+            eprintln!("Saved {}", filename.display());
         }
-        let seed = self.seed.as_ref().expect("seed");
+        Ok(())
+    }
 
-        let input = cli
-            .get(format!(
-                "https://everybody.codes/assets/2025/{day}/input/{seed}.json"
-            ))
-            .call()
-            .expect("request failed")
-            .body_mut()
-            .read_json::<InputData>()
-            .expect("json");
-
-        for ((contents, key), part) in [
-            (&input.first, &keys.key1),
-            (&input.second, &keys.key2),
-            (&input.third, &keys.key3),
-        ]
-        .into_iter()
-        .zip(1..)
-        {
-            let Some(key) = key else {
-                println!("No key for part {part}. Skipping.");
+    /// Downloads the descriptions of the unlocked parts, saving them as Markdown along with the
+    /// examples they contain. See [`Self::read_examples`]. The keys are refreshed, as for
+    /// [`Self::download`].
+    pub fn describe(&mut self, day: u16) -> Result<(), RunnerError> {
+        let keys = self.keys(day, true)?;
+        let unlocked = [keys.key1, keys.key2, keys.key3]
+            .into_iter()
+            .zip(1..)
+            .filter_map(|(key, part)| Some((part, key?)))
+            .collect::<Vec<_>>();
+
+        let parts = unlocked.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+        let descriptions = self.cached_parts(day, "description", &parts)?;
+
+        for (part, key) in unlocked {
+            let Some(encrypted) = descriptions.part(part) else {
+                eprintln!("No description for part {part}. Skipping.");
                 continue;
             };
-            let key_bytes = key.as_bytes();
-            let iv = &key_bytes[..16];
-            let cihper = Decryptor::<Aes256>::new(key_bytes.into(), iv.into());
-            let mut buf = vec![0_u8; contents.len()];
-            let decrypted = cihper
-                .clone()
-                .decrypt_padded_b2b_mut::<Pkcs7>(contents, &mut buf)
-                .expect("Decrypt input files");
+            let html = decrypt_part(encrypted, &key).map_err(|source| RunnerError::Decryption {
+                day,
+                part,
+                source,
+            })?;
+            let description = Description::from_html(&html);
 
-            let filename = format!("./input/day_{day:02}_part_{part}.txt");
-            std::fs::write(&filename, decrypted).expect("Write input files");
-            println!("Saved {filename}");
+            let filename = self
+                .event_dir()
+                .join(format!("day_{day:02}_part_{part}_description.md"));
+            std::fs::write(&filename, &description.markdown).map_err(RunnerError::io(&filename))?;
+            eprintln!("Saved {}", filename.display());
+            self.remove_examples(day, part)?;
+            for (example, number) in description.examples.iter().zip(1..) {
+                let filename = self.example_path(day, part, number);
+                std::fs::write(&filename, &example.input).map_err(RunnerError::io(&filename))?;
+                if let Some(expected) = &example.expected {
+                    let filename = self.example_answer_path(day, part, number);
+                    std::fs::write(&filename, expected).map_err(RunnerError::io(&filename))?;
+                }
+            }
+            eprintln!(
+                "Extracted {} examples of part {part}",
+                description.examples.len()
+            );
         }
+        Ok(())
     }
 
-    pub fn run<D: Day>(&mut self, day: u16, part_filter: Option<u16>, repeat: Option<u32>) {
-        println!();
-        for part in 1..=3 {
-            if part_filter.is_none_or(|p| p == part) {
-                let filename = format!("./input/day_{day:02}_part_{part}.txt");
-                if !std::fs::exists(&filename).unwrap() {
-                    self.download(day);
-                }
-                let time_start = Instant::now();
-                let input_text = std::fs::read_to_string(filename).unwrap();
-                let input = match D::parse(&input_text) {
-                    Ok(input) => input,
-                    Err(err) => {
-                        println!("Parse error: {err}");
-                        continue;
-                    }
-                };
-                let repeat = repeat.unwrap_or(1).max(1);
-                let time_parsed = Instant::now();
-                match part {
-                    1 => {
-                        let result = black_box(D::part_1(&input));
-                        for _ in 1..repeat {
-                            black_box(D::part_1(&input));
-                        }
-                        println!("Quest {day} - Part 1: {result}");
-                    }
-                    2 => {
-                        let result = black_box(D::part_2(&input));
-                        for _ in 1..repeat {
-                            black_box(D::part_2(&input));
-                        }
-                        println!("Quest {day} - Part 2: {result}");
+    /// The encrypted `input` or `description` of a day, from the cache unless it lacks one of
+    /// the `parts`. Parts unlocked since the last time are missing from the cached copy.
+    fn cached_parts(
+        &mut self,
+        day: u16,
+        name: &str,
+        parts: &[u16],
+    ) -> Result<InputData, RunnerError> {
+        let filename = self.cache_path(day, name);
+        let cached = std::fs::read_to_string(&filename)
+            .ok()
+            .and_then(|json| serde_json::from_str::<InputData>(&json).ok())
+            .filter(|cached| parts.iter().all(|&part| cached.part(part).is_some()));
+        if let Some(cached) = cached {
+            return Ok(cached);
+        }
+        let seed = self.get_seed()?;
+        let url = format!(
+            "{}assets/{}/{day}/{name}/{seed}.json",
+            self.base_url,
+            self.event.id()
+        );
+        self.fetch_to_cache(&url, &filename)
+    }
+
+    /// The keys of the unlocked parts. Fetching them also stores the known answers.
+    fn keys(&mut self, day: u16, refresh: bool) -> Result<Keys, RunnerError> {
+        let keys_fn = self.cache_path(day, "keys");
+        if !refresh && let Ok(keys_json) = std::fs::read_to_string(&keys_fn) {
+            return Ok(serde_json::from_str(&keys_json)?);
+        }
+        let url = format!("{}api/event/{}/quest/{day}", self.base_url, self.event.id());
+        let keys: Keys = self.fetch_to_cache(&url, &keys_fn)?;
+        for (answer, part) in [&keys.answer1, &keys.answer2, &keys.answer3]
+            .into_iter()
+            .zip(1..)
+        {
+            if let Some(answer) = answer
+                && self.known_answer(day, part).as_ref() != Some(answer)
+            {
+                self.save_answer(day, part, answer)?;
+            }
+        }
+        Ok(keys)
+    }
+
+    /// Refreshes the keys of a quest when one of the `parts` has no known answer, since it may
+    /// have been solved after the keys were fetched. Nothing is fetched when offline.
+    pub fn refresh_answers(&mut self, day: u16, parts: &[u16]) -> Result<(), RunnerError> {
+        if self.offline
+            || parts
+                .iter()
+                .all(|&part| self.known_answer(day, part).is_some())
+        {
+            return Ok(());
+        }
+        self.keys(day, true).map(drop)
+    }
+
+    /// Fetches a JSON document, keeping a copy of the raw response in `filename`. The copy is
+    /// only written once the response parses, so a malformed one is not used again.
+    fn fetch_to_cache<T: DeserializeOwned>(
+        &mut self,
+        url: &str,
+        filename: &Path,
+    ) -> Result<T, RunnerError> {
+        let cli = self.cli_with_cookie()?;
+        let json = cli.get(url)?.body_mut().read_to_string()?;
+        let parsed = serde_json::from_str(&json)?;
+        self.create_event_dir()?;
+        std::fs::write(filename, &json).map_err(RunnerError::io(filename))?;
+        Ok(parsed)
+    }
+
+    /// The progress of each part of a quest, from the stored answers. See
+    /// [`Self::refresh_answers`] to store those accepted since. The completion times are only
+    /// known for the answers submitted from here.
+    pub fn progress(&self, day: u16) -> Result<[PartProgress; 3], RunnerError> {
+        let log = SubmissionLog::load(&self.submissions_path())?;
+        Ok([1, 2, 3].map(|part| PartProgress {
+            part,
+            answer: self.known_answer(day, part),
+            completed: log.accepted_at(day, part),
+        }))
+    }
+
+    fn submissions_path(&self) -> PathBuf {
+        self.event_dir().join("submissions.json")
+    }
+
+    pub fn submit(&mut self, day: u16, part: u16, answer: &str) -> Result<(), RunnerError> {
+        let log_fn = self.submissions_path();
+        let mut log = SubmissionLog::load(&log_fn)?;
+        if let Some(previous) = log.blocking_attempt(day, part, answer) {
+            println!(
+                "Quest {day} - Part {part}: not submitting {answer}, {} was already {}",
+                previous.answer, previous.feedback
+            );
+            return Ok(());
+        }
+
+        let cli = self.cli_with_cookie()?;
+        let feedback = post_answer(
+            &cli,
+            &format!(
+                "{}api/event/{}/quest/{day}/part/{part}/answer",
+                self.base_url,
+                self.event.id()
+            ),
+            answer,
+        )?;
+        println!("Quest {day} - Part {part}: {answer} is {feedback}");
+
+        if feedback == Feedback::Correct {
+            self.save_answer(day, part, answer)?;
+            // The next part is unlocked now, so have the next run refresh the keys.
+            let keys_fn = self.cache_path(day, "keys");
+            if keys_fn.exists() {
+                std::fs::remove_file(&keys_fn).map_err(RunnerError::io(&keys_fn))?;
+            }
+        }
+        log.record(day, part, answer, feedback);
+        self.create_event_dir()?;
+        log.save(&log_fn)
+    }
+
+    /// Downloads the quest if the input of the part is missing. A part that was locked the last
+    /// time is reported as locked without asking the server again. When offline, only the
+    /// cached payload is decrypted, failing with [`RunnerError::Offline`] if anything is missing.
+    pub fn ensure_input(&mut self, day: u16, part: u16) -> Result<(), RunnerError> {
+        let filename = self.input_path(day, part);
+        if std::fs::exists(&filename).map_err(RunnerError::io(&filename))? {
+            return Ok(());
+        }
+        self.fetch_quest(day, false)?;
+        if std::fs::exists(&filename).map_err(RunnerError::io(&filename))? {
+            Ok(())
+        } else {
+            Err(RunnerError::LockedPart { day, part })
+        }
+    }
+
+    /// The downloaded input of a part, if any. Nothing is downloaded; see [`Self::ensure_input`].
+    pub fn read_input(&self, day: u16, part: u16) -> Option<String> {
+        std::fs::read_to_string(self.input_path(day, part)).ok()
+    }
+
+    /// Runs a single part on the given input, reporting the answer along with the time of each
+    /// sample.
+    pub fn run_input<D: Day + 'static>(
+        &self,
+        day: u16,
+        part: u16,
+        input_text: String,
+        sampling: Sampling,
+    ) -> PartReport {
+        let pairs = self
+            .params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()));
+        let params = match D::Params::from_pairs(pairs) {
+            Ok(params) => params,
+            Err(err) => return PartReport::new(day, part, Status::InvalidParams).with_error(&err),
+        };
+        // The thread can not be stopped, so a timed out part keeps running in the background,
+        // and the parts after it are measured under contention.
+        let contended_at_start = self.abandoned.load(Ordering::SeqCst) > 0;
+        let contended = |report: PartReport| {
+            report.contended(contended_at_start || self.abandoned.load(Ordering::SeqCst) > 0)
+        };
+        let Some(timeout) = self.timeout else {
+            return contended(solve_part::<D>(day, part, &input_text, &params, sampling));
+        };
+        let (sender, receiver) = mpsc::channel();
+        let abandoned = Arc::clone(&self.abandoned);
+        abandoned.fetch_add(1, Ordering::SeqCst);
+        thread::spawn(move || {
+            let report = solve_part::<D>(day, part, &input_text, &params, sampling);
+            abandoned.fetch_sub(1, Ordering::SeqCst);
+            let _ = sender.send(report);
+        });
+        receiver.recv_timeout(timeout).map_or_else(
+            |_| PartReport::new(day, part, Status::Timeout).with_error(&format!("{timeout:?}")),
+            contended,
+        )
+    }
+}
+
+fn solve_part<D: Day>(
+    day: u16,
+    part: u16,
+    input_text: &str,
+    params: &D::Params,
+    sampling: Sampling,
+) -> PartReport {
+    let time_start = Instant::now();
+    let (input, parse_mem) = memory::measure(|| catch_quietly(|| D::parse(input_text)));
+    let input = match input {
+        Ok(Ok(input)) => input,
+        Ok(Err(err)) => return PartReport::new(day, part, Status::ParseError).with_error(&err),
+        Err(message) => return PartReport::new(day, part, Status::Failed).with_error(&message),
+    };
+    let parse_time = time_start.elapsed();
+    let mut sample_times = Vec::new();
+    let answer = catch_quietly(|| match part {
+        1 => sample(
+            || D::part_1_with(&input, params),
+            sampling,
+            &mut sample_times,
+        ),
+        2 => sample(
+            || D::part_2_with(&input, params),
+            sampling,
+            &mut sample_times,
+        ),
+        _ => sample(
+            || D::part_3_with(&input, params),
+            sampling,
+            &mut sample_times,
+        ),
+    });
+    match answer {
+        Ok((answer, solve_mem)) => PartReport {
+            answer: Some(answer),
+            parse_time,
+            parse_mem,
+            solve_mem,
+            ..PartReport::new(day, part, Status::Ok).with_sample_times(sample_times)
+        },
+        Err(message) => PartReport {
+            parse_time,
+            parse_mem,
+            ..PartReport::new(day, part, Status::Failed).with_error(&message)
+        },
+    }
+}
+
+/// Reads an input given on the command line, `-` being stdin. Unlike the downloaded inputs,
+/// these usually end with a newline, which is stripped.
+pub fn read_input_file(path: &Path) -> Result<String, RunnerError> {
+    let input_text = if path.as_os_str() == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    }
+    .map_err(RunnerError::io(path))?;
+    Ok(input_text.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Parses an input without solving anything, turning a parse error or a panic into its message.
+pub fn parse_only<D: Day>(input_text: &str) -> Result<(), String> {
+    match catch_quietly(|| D::parse(input_text).map(drop)) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(message) => Err(format!("panicked: {message}")),
+    }
+}
+
+/// How many times each part is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sampling {
+    /// Untimed runs before the timed samples
+    pub warmup: u32,
+    pub samples: u32,
+}
+
+impl Sampling {
+    pub const fn repeat(samples: u32) -> Self {
+        Self { warmup: 0, samples }
+    }
+}
+
+fn sample<T: Display>(
+    solve: impl Fn() -> T,
+    sampling: Sampling,
+    sample_times: &mut Vec<Duration>,
+) -> (String, Option<MemStats>) {
+    for _ in 0..sampling.warmup {
+        black_box(solve());
+    }
+    let time_start = Instant::now();
+    let (result, mem) = memory::measure(|| black_box(solve()));
+    sample_times.push(time_start.elapsed());
+    for _ in 1..sampling.samples {
+        let time_start = Instant::now();
+        black_box(solve());
+        sample_times.push(time_start.elapsed());
+    }
+    (result.to_string(), mem)
+}
+
+thread_local! {
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keeps the default panic output for panics other than those caught and reported per part.
+/// Only the first call installs the hook.
+pub fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.get() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into its message.
+fn catch_quietly<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let was_quiet = QUIET_PANICS.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(f));
+    QUIET_PANICS.set(was_quiet);
+    result.map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+    use crate::crypto::tests::KEY;
+
+    /// Minimal stand-in for the everybody.codes server. Answers each incoming request with the
+    /// next canned `(status, body)` pair, and returns the raw requests it received.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        serve_with_headers(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, "", body))
+                .collect(),
+        )
+    }
+
+    /// Like [`serve`], with extra header lines for each response. A status of zero closes the
+    /// connection without responding.
+    fn serve_with_headers(
+        responses: Vec<(u16, &'static str, String)>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, headers, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
                     }
-                    _ => {
-                        let result = black_box(D::part_3(&input));
-                        for _ in 1..repeat {
-                            black_box(D::part_3(&input));
-                        }
-                        println!("Quest {day} - Part 3: {result}");
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
                     }
                 }
-                let time_complete = Instant::now();
-                println!(
-                    "          parsing: {:?}",
-                    time_parsed.duration_since(time_start)
-                );
-                print!(
-                    "          runner: {:?}",
-                    time_complete.duration_since(time_parsed) / repeat
-                );
-                if repeat > 1 {
-                    println!(" ({repeat} samples)");
-                } else {
-                    println!();
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8(request_body).unwrap());
+                requests.push(request);
+
+                if status == 0 {
+                    continue;
                 }
-                println!();
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Stand-in\r\n\
+                    {headers}\
+                    Content-Type: application/json\r\n\
+                    Content-Length: {}\r\n\
+                    Connection: close\r\n\
+                    \r\n\
+                    {body}",
+                    body.len()
+                )
+                .unwrap();
             }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn request_json(request: &str) -> serde_json::Value {
+        let (_, body) = request.split_once("\r\n\r\n").unwrap();
+        serde_json::from_str(body).unwrap()
+    }
+
+    #[test]
+    fn test_post_answer() {
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                r#"{"correct":false,"lengthCorrect":true,"firstCorrect":false}"#.to_string(),
+            ),
+            (
+                200,
+                r#"{"correct":true,"lengthCorrect":true,"firstCorrect":true}"#.to_string(),
+            ),
+        ]);
+        let cli = Client::new(&HttpConfig::default()).unwrap();
+        let url = format!("{base_url}api/event/2025/quest/3/part/2/answer");
+
+        let feedback = post_answer(&cli, &url, "1234").unwrap();
+        assert_eq!(
+            feedback,
+            Feedback::Wrong {
+                length_correct: true,
+                first_correct: false
+            }
+        );
+        let feedback = post_answer(&cli, &url, "5678").unwrap();
+        assert_eq!(feedback, Feedback::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /api/event/2025/quest/3/part/2/answer "));
+        assert_eq!(request_json(&requests[0])["answer"], "1234");
+        assert_eq!(request_json(&requests[1])["answer"], "5678");
+    }
+
+    /// Nothing listens on the discard port, so any request fails with a network error.
+    pub const UNREACHABLE_URL: &str = "http://127.0.0.1:9/";
+
+    /// A fresh input directory for the test `name`, which has to be unique across the crate.
+    pub fn temp_input_root(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ec-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A runner of the 2025 event with a fresh input directory, and the cookie `secret` saved.
+    pub fn test_runner(name: &str, base_url: &str) -> (Runner, PathBuf) {
+        let input_root = temp_input_root(name);
+        let mut runner = Runner::new(Event::Main(2025), base_url, &input_root);
+        runner.set_cookie_file(Some(input_root.join("cookie.txt")));
+        runner.save_cookie("secret").unwrap();
+        (runner, input_root)
+    }
+
+    fn encrypt(plain_text: &str, key: &str) -> String {
+        hex::encode(crate::crypto::encrypt_part(plain_text.as_bytes(), key))
+    }
+
+    #[test]
+    fn test_read_input_file() {
+        let input_root = temp_input_root("input-file");
+        std::fs::create_dir_all(&input_root).unwrap();
+        let filename = input_root.join("input.txt");
+        std::fs::write(&filename, "10,5,1\r\n").unwrap();
+        assert_eq!(read_input_file(&filename).unwrap(), "10,5,1");
+        std::fs::write(&filename, "#.\n.#\n\n").unwrap();
+        assert_eq!(read_input_file(&filename).unwrap(), "#.\n.#");
+        assert!(matches!(
+            read_input_file(&input_root.join("missing.txt")),
+            Err(RunnerError::Io { .. })
+        ));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_input() {
+        let input_root = temp_input_root("legacy-input");
+        std::fs::create_dir_all(&input_root).unwrap();
+        std::fs::write(input_root.join("day_01_part_1.txt"), "old").unwrap();
+        let mut runner = Runner::new(Event::Main(2025), DEFAULT_BASE_URL, &input_root);
+        runner.set_offline(true);
+        runner.ensure_input(1, 1).unwrap();
+        assert_eq!(runner.read_input(1, 1).as_deref(), Some("old"));
+
+        std::fs::create_dir_all(input_root.join("2025")).unwrap();
+        std::fs::write(input_root.join("2025").join("day_01_part_1.txt"), "new").unwrap();
+        assert_eq!(runner.read_input(1, 1).as_deref(), Some("new"));
+
+        let runner = Runner::new(Event::Main(2024), DEFAULT_BASE_URL, &input_root);
+        assert_eq!(runner.read_input(1, 1), None);
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_cookie_precedence() {
+        let input_root = temp_input_root("cookie-precedence");
+        std::fs::create_dir_all(&input_root).unwrap();
+        let mut runner = Runner::new(Event::Main(2025), DEFAULT_BASE_URL, &input_root);
+        runner.set_cookie_file(Some(input_root.join("cookie.txt")));
+        assert_eq!(
+            runner.read_cookie(Some("from-env".into())).unwrap(),
+            "from-env"
+        );
+        assert!(matches!(
+            runner.read_cookie(Some(" ".into())),
+            Err(RunnerError::MissingCookie)
+        ));
+        runner.save_cookie("from-file").unwrap();
+        assert_eq!(
+            runner.read_cookie(Some("from-env".into())).unwrap(),
+            "from-file"
+        );
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_base_url() {
+        let mut runner = Runner::new(Event::Main(2025), "not a url", "./input");
+        runner.cookie = Some(Arc::from("everybody-codes=secret"));
+        assert!(matches!(
+            runner.whoami(),
+            Err(RunnerError::InvalidBaseUrl(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cookie_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let input_root = temp_input_root("cookie-permissions");
+        std::fs::create_dir_all(&input_root).unwrap();
+        let cookie_fn = input_root.join("cookie.txt");
+        std::fs::write(&cookie_fn, "old").unwrap();
+        std::fs::set_permissions(&cookie_fn, PermissionsExt::from_mode(0o644)).unwrap();
+        let mut runner = Runner::new(Event::Main(2025), DEFAULT_BASE_URL, &input_root);
+        runner.set_cookie_file(Some(cookie_fn.clone()));
+        runner.save_cookie("secret").unwrap();
+
+        let metadata = std::fs::metadata(&cookie_fn).unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+        assert_eq!(std::fs::read_to_string(&cookie_fn).unwrap(), "secret");
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_event() {
+        assert_eq!("2024".parse::<Event>().unwrap(), Event::Main(2024));
+        assert_eq!("2025".parse::<Event>().unwrap(), Event::Main(2025));
+        assert_eq!("story-1".parse::<Event>().unwrap(), Event::Story(1));
+        assert_eq!("story2".parse::<Event>().unwrap(), Event::Story(2));
+        assert!("1999".parse::<Event>().is_err());
+        assert!("story-0".parse::<Event>().is_err());
+        assert!("stories".parse::<Event>().is_err());
+        assert_eq!(Event::Story(1).to_string(), "story-1");
+    }
+
+    #[test]
+    fn test_download() {
+        let key2 = "ABCDEFGHIJKLMNOPQRSTUVWXYZ012345";
+        let (base_url, server) = serve(vec![
+            (
+                200,
+                format!(
+                    r#"{{"key1":"{KEY}","key2":"{key2}","key3":null,"answer1":"1234","answer2":null,"answer3":null}}"#
+                ),
+            ),
+            (200, r#"{"seed":42}"#.to_string()),
+            (
+                200,
+                format!(
+                    r#"{{"1":"{}","2":"{}","3":"{}"}}"#,
+                    encrypt("first part", KEY),
+                    encrypt("second part", key2),
+                    encrypt("third part", key2),
+                ),
+            ),
+        ]);
+        let (mut runner, input_root) = test_runner("download", base_url.trim_end_matches('/'));
+        runner.event = Event::Story(2);
+        runner.download(3).unwrap();
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /api/event/2/quest/3 "));
+        assert!(requests[0].contains("everybody-codes=secret"));
+        assert!(requests[1].starts_with("GET /api/user/me "));
+        assert!(requests[2].starts_with("GET /assets/2/3/input/42.json "));
+
+        let event_dir = input_root.join("story-2");
+        assert_eq!(
+            std::fs::read_to_string(event_dir.join("day_03_part_1.txt")).unwrap(),
+            "first part"
+        );
+        assert_eq!(
+            std::fs::read_to_string(event_dir.join("day_03_part_2.txt")).unwrap(),
+            "second part"
+        );
+        assert!(!event_dir.join("day_03_part_3.txt").exists());
+        assert_eq!(runner.known_answer(3, 1).as_deref(), Some("1234"));
+        assert_eq!(runner.known_answer(3, 2), None);
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_download_errors() {
+        let input_root = temp_input_root("download-errors");
+        let mut runner = Runner::new(Event::Main(2025), UNREACHABLE_URL, &input_root);
+        runner.set_cookie_file(Some(input_root.join("cookie.txt")));
+        assert!(matches!(
+            runner.download(1),
+            Err(RunnerError::MissingCookie)
+        ));
+
+        let (base_url, server) = serve(vec![
+            (404, "Not found".to_string()),
+            (
+                200,
+                format!(r#"{{"key1":"{KEY}","key2":null,"key3":null}}"#),
+            ),
+            (200, r#"{"seed":42}"#.to_string()),
+            (
+                200,
+                format!(
+                    r#"{{"1":"{}","2":"00","3":"00"}}"#,
+                    encrypt("first part", KEY)
+                ),
+            ),
+        ]);
+        let (mut runner, input_root) = test_runner("download-errors", &base_url);
+        let err = runner.download(1).unwrap_err();
+        assert!(matches!(err, RunnerError::HttpStatus(404)));
+        assert_eq!(err.exit_code(), 111);
+
+        assert!(matches!(
+            runner.ensure_input(2, 2),
+            Err(RunnerError::LockedPart { day: 2, part: 2 })
+        ));
+        assert!(runner.ensure_input(2, 1).is_ok());
+        server.join().unwrap();
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_response() {
+        let (base_url, server) = serve(vec![
+            (200, "<html>Maintenance</html>".to_string()),
+            (200, format!(r#"{{"key1":"{KEY}"}}"#)),
+            (200, r#"{"seed":42}"#.to_string()),
+            (200, format!(r#"{{"1":"{}"}}"#, encrypt("first part", KEY))),
+        ]);
+        let (mut runner, input_root) = test_runner("malformed", &base_url);
+
+        assert!(matches!(runner.download(1), Err(RunnerError::Json(_))));
+        assert!(!runner.cache_path(1, "keys").exists());
+        // The next attempt fetches the keys again, rather than failing on the cached copy.
+        runner.ensure_input(1, 1).unwrap();
+        assert_eq!(runner.read_input(1, 1).as_deref(), Some("first part"));
+        assert_eq!(server.join().unwrap().len(), 4);
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_incremental_download() {
+        let key2 = "ABCDEFGHIJKLMNOPQRSTUVWXYZ012345";
+        let (base_url, server) = serve(vec![
+            (200, format!(r#"{{"key1":"{KEY}"}}"#)),
+            (200, r#"{"seed":42}"#.to_string()),
+            (
+                200,
+                format!(
+                    r#"{{"1":"{}","2":"{}","3":"00"}}"#,
+                    encrypt("first part", KEY),
+                    encrypt("second part", key2),
+                ),
+            ),
+            (200, format!(r#"{{"key1":"{KEY}","key2":"{key2}"}}"#)),
+        ]);
+        let (mut runner, input_root) = test_runner("incremental", &base_url);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                runner.ensure_input(1, 2),
+                Err(RunnerError::LockedPart { day: 1, part: 2 })
+            ));
+        }
+        assert_eq!(runner.read_input(1, 1).as_deref(), Some("first part"));
+
+        // As after a correct submission of part 1
+        std::fs::remove_file(runner.cache_path(1, "keys")).unwrap();
+        runner.ensure_input(1, 2).unwrap();
+        assert_eq!(runner.read_input(1, 2).as_deref(), Some("second part"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[3].starts_with("GET /api/event/2025/quest/1 "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_stale_input_cache() {
+        let key2 = "ABCDEFGHIJKLMNOPQRSTUVWXYZ012345";
+        let (base_url, server) = serve(vec![
+            (200, format!(r#"{{"key1":"{KEY}","key2":"{key2}"}}"#)),
+            (200, r#"{"seed":42}"#.to_string()),
+            (
+                200,
+                format!(
+                    r#"{{"1":"{}","2":"{}"}}"#,
+                    encrypt("first part", KEY),
+                    encrypt("second part", key2),
+                ),
+            ),
+        ]);
+        let (mut runner, input_root) = test_runner("stale-input", &base_url);
+        // Cached while only part 1 was published
+        let cached = format!(r#"{{"1":"{}"}}"#, encrypt("first part", KEY));
+        std::fs::create_dir_all(input_root.join("2025")).unwrap();
+        std::fs::write(runner.cache_path(1, "input"), cached).unwrap();
+
+        runner.download(1).unwrap();
+        assert_eq!(runner.read_input(1, 2).as_deref(), Some("second part"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[2].starts_with("GET /assets/2025/1/input/42.json "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_offline() {
+        let (mut runner, input_root) = test_runner("offline", UNREACHABLE_URL);
+        runner.set_offline(true);
+        runner.create_event_dir().unwrap();
+        let keys_json = format!(r#"{{"key1":"{KEY}"}}"#);
+        std::fs::write(runner.cache_path(1, "keys"), keys_json).unwrap();
+        let input_json = format!(r#"{{"1":"{}","2":"00","3":"00"}}"#, encrypt("cached", KEY));
+        std::fs::write(runner.cache_path(1, "input"), input_json).unwrap();
+
+        runner.ensure_input(1, 1).unwrap();
+        assert_eq!(runner.read_input(1, 1).as_deref(), Some("cached"));
+        assert!(matches!(
+            runner.ensure_input(1, 2),
+            Err(RunnerError::LockedPart { day: 1, part: 2 })
+        ));
+        assert!(matches!(
+            runner.ensure_input(2, 1),
+            Err(RunnerError::Offline)
+        ));
+        assert!(matches!(runner.download(1), Err(RunnerError::Offline)));
+        assert!(matches!(runner.whoami(), Err(RunnerError::Offline)));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_retries() {
+        let (base_url, server) = serve_with_headers(vec![
+            (0, "", String::new()),
+            (503, "Retry-After: 1\r\n", String::new()),
+            (200, "", r#"{"name":"Fyrryn","seed":42}"#.to_string()),
+            (429, "", String::new()),
+            (429, "", String::new()),
+            (0, "", String::new()),
+            (504, "", String::new()),
+            (503, "Retry-After: 3600\r\n", String::new()),
+        ]);
+        let (mut runner, input_root) = test_runner("retries", &base_url);
+        runner.set_http_config(HttpConfig {
+            retries: 2,
+            backoff: Duration::from_millis(10),
+            ..HttpConfig::default()
+        });
+
+        let start = Instant::now();
+        assert_eq!(runner.whoami().unwrap().seed, 42);
+        assert!(
+            start.elapsed() >= Duration::from_secs(1),
+            "Honors Retry-After"
+        );
+
+        runner.set_http_config(HttpConfig {
+            retries: 1,
+            backoff: Duration::from_millis(10),
+            ..HttpConfig::default()
+        });
+        assert!(matches!(runner.whoami(), Err(RunnerError::HttpStatus(429))));
+
+        // A dropped answer submission is not sent again.
+        assert!(matches!(
+            runner.submit(3, 1, "1234"),
+            Err(RunnerError::Network(_))
+        ));
+        // Neither is one the gateway timed out on, since the server may have handled it.
+        assert!(matches!(
+            runner.submit(3, 1, "5678"),
+            Err(RunnerError::HttpStatus(504))
+        ));
+        // Waiting longer than the timeouts of all the retries is not worth it.
+        assert!(matches!(runner.whoami(), Err(RunnerError::HttpStatus(503))));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 8);
+        assert!(requests[5].starts_with("POST /api/event/2025/quest/3/part/1/answer "));
+        assert!(requests[6].starts_with("POST /api/event/2025/quest/3/part/1/answer "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = UNIX_EPOCH + Duration::from_secs(784_111_777);
+        let parse = |value| parse_retry_after(value, now).map(|delay| delay.as_secs());
+        assert_eq!(parse(" 120 "), Some(120));
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 GMT"), Some(0));
+        assert_eq!(parse("Sun, 06 Nov 1994 08:51:37 GMT"), Some(120));
+        assert_eq!(parse("Thu, 29 Feb 2024 00:00:00 GMT"), Some(925_053_023));
+        assert_eq!(parse("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+        assert_eq!(parse("soon"), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        let html = "<p>Example:</p><pre>1,2\n3</pre><p>The answer is <b>6</b>.</p>";
+        let (base_url, server) = serve(vec![
+            (200, format!(r#"{{"key1":"{KEY}"}}"#)),
+            (200, r#"{"seed":42}"#.to_string()),
+            (200, format!(r#"{{"1":"{}"}}"#, encrypt(html, KEY))),
+            (200, format!(r#"{{"key1":"{KEY}"}}"#)),
+        ]);
+        let (mut runner, input_root) = test_runner("describe", &base_url);
+
+        runner.describe(5).unwrap();
+        let markdown =
+            std::fs::read_to_string(input_root.join("2025/day_05_part_1_description.md")).unwrap();
+        assert_eq!(
+            markdown,
+            "Example:\n\n```\n1,2\n3\n```\n\nThe answer is **6**.\n"
+        );
+        assert_eq!(
+            runner.read_examples(5),
+            [ExampleFixture {
+                part: 1,
+                number: 1,
+                input: "1,2\n3".to_string(),
+                expected: Some("6".to_string()),
+            }]
+        );
+        // The keys are fetched again, but the cached descriptions are used. Examples that are not
+        // in the description are removed.
+        std::fs::write(input_root.join("2025/day_05_part_1_example_2.txt"), "4").unwrap();
+        runner.describe(5).unwrap();
+        assert_eq!(runner.read_examples(5).len(), 1);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[2].starts_with("GET /assets/2025/5/description/42.json "));
+        assert!(requests[3].starts_with("GET /api/event/2025/quest/5 "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_progress() {
+        let (base_url, server) = serve(vec![
+            (200, r#"{"correct":true}"#.to_string()),
+            (
+                200,
+                format!(r#"{{"key1":"{KEY}","key2":"{KEY}","answer1":"1234","answer2":"ab"}}"#),
+            ),
+        ]);
+        let (mut runner, input_root) = test_runner("progress", &base_url);
+
+        runner.submit(3, 2, "ab").unwrap();
+        runner.refresh_answers(3, &[1, 2, 3]).unwrap();
+        let progress = runner.progress(3).unwrap();
+        // Solved on the site, and from here
+        assert_eq!(progress[0].answer.as_deref(), Some("1234"));
+        assert_eq!(progress[0].completed, None);
+        assert_eq!(progress[1].answer.as_deref(), Some("ab"));
+        assert!(progress[1].completed.is_some());
+        assert_eq!(progress[2].answer, None);
+        // Only the keys of quests with unknown answers are refreshed.
+        runner.refresh_answers(3, &[1, 2]).unwrap();
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].starts_with("GET /api/event/2025/quest/3 "));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_whoami() {
+        let (base_url, server) = serve(vec![
+            (200, r#"{"id":1,"name":"Mizard","seed":7}"#.to_string()),
+            (401, String::new()),
+            (200, r#"{"seed":0}"#.to_string()),
+        ]);
+        let (mut runner, input_root) = test_runner("whoami", &base_url);
+        // Saved without the newline
+        runner.save_cookie("secret\n").unwrap();
+
+        let user = runner.whoami().unwrap();
+        assert_eq!(user.name.as_deref(), Some("Mizard"));
+        assert_eq!(user.seed, 7);
+        assert!(matches!(runner.whoami(), Err(RunnerError::ExpiredSession)));
+        assert!(matches!(runner.whoami(), Err(RunnerError::ExpiredSession)));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].contains("everybody-codes=secret"));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_submission_guard() {
+        let wrong = Feedback::Wrong {
+            length_correct: false,
+            first_correct: false,
+        };
+        let mut log = SubmissionLog::default();
+        log.record(1, 1, "123", wrong);
+        assert!(log.blocking_attempt(1, 1, "123").is_some());
+        assert!(log.blocking_attempt(1, 1, "456").is_none());
+        assert!(log.blocking_attempt(1, 2, "123").is_none());
+        assert!(log.blocking_attempt(2, 1, "123").is_none());
+
+        log.record(1, 1, "456", Feedback::Correct);
+        let previous = log.blocking_attempt(1, 1, "789").unwrap();
+        assert_eq!(previous.answer, "456");
+
+        let json = serde_json::to_string(&log).unwrap();
+        assert_eq!(serde_json::from_str::<SubmissionLog>(&json).unwrap(), log);
+
+        let input_root = temp_input_root("submission-log");
+        assert_eq!(
+            SubmissionLog::load(&input_root.join("submissions.json")).unwrap(),
+            SubmissionLog::default()
+        );
+        // A directory can not be read as the log.
+        std::fs::create_dir_all(&input_root).unwrap();
+        assert!(matches!(
+            SubmissionLog::load(&input_root),
+            Err(RunnerError::Io { .. })
+        ));
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    struct Sleepy;
+
+    impl Day for Sleepy {
+        type Input = u64;
+        type ParseError = std::num::ParseIntError;
+        type Params = ();
+        const PARTS: &[u16] = &[1];
+
+        fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+            input.trim().parse()
+        }
+
+        fn part_1(input: &Self::Input) -> impl Display {
+            std::thread::sleep(Duration::from_millis(*input));
+            input
         }
     }
+
+    #[test]
+    fn test_params() {
+        use crate::day_13::Day13;
+
+        let input = "72\n58\n47\n61\n67".to_string();
+        let runner = Runner::default().with_params(&[("turns", "2025")]);
+        let report = runner.run_input::<Day13>(13, 2, input.clone(), Sampling::repeat(1));
+        assert_eq!(report.answer.as_deref(), Some("67"));
+
+        let runner = runner.with_params(&[("cycles", "10")]);
+        let report = runner.run_input::<Day13>(13, 2, input, Sampling::repeat(1));
+        assert_eq!(report.status, Status::InvalidParams);
+        assert_eq!(
+            report.error.as_deref(),
+            Some("Unknown parameter \"cycles\"")
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let input_root = temp_input_root("timeout");
+        let mut runner = Runner::new(Event::Main(2025), DEFAULT_BASE_URL, &input_root);
+        std::fs::create_dir_all(runner.event_dir()).unwrap();
+        runner.set_timeout(Some(Duration::from_millis(500)));
+
+        let report = runner.run_input::<Sleepy>(1, 1, "10".to_string(), Sampling::repeat(1));
+        assert_eq!(report.status, Status::Ok);
+        assert_eq!(report.answer.as_deref(), Some("10"));
+
+        assert!(!report.parallel);
+
+        let report = runner.run_input::<Sleepy>(1, 1, "5000".to_string(), Sampling::repeat(1));
+        assert_eq!(report.status, Status::Timeout);
+        assert_eq!(report.answer, None);
+
+        // The timed out part is still running alongside.
+        let report = runner.run_input::<Sleepy>(1, 1, "10".to_string(), Sampling::repeat(1));
+        assert_eq!(report.status, Status::Ok);
+        assert!(report.parallel);
+
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+
+    #[test]
+    fn test_panic_isolation() {
+        let input_root = temp_input_root("panic");
+        let runner = Runner::new(Event::Main(2025), DEFAULT_BASE_URL, &input_root);
+        std::fs::create_dir_all(runner.event_dir()).unwrap();
+
+        // Part 2 falls back to the `todo!()` default.
+        let report = runner.run_input::<Sleepy>(1, 2, "10".to_string(), Sampling::repeat(1));
+        assert_eq!(report.status, Status::Failed);
+        assert_eq!(report.error.as_deref(), Some("not yet implemented"));
+
+        let report = runner.run_input::<Sleepy>(1, 1, "ten".to_string(), Sampling::repeat(1));
+        assert_eq!(report.status, Status::ParseError);
+
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
 }
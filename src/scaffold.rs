@@ -0,0 +1,283 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::runner::{ExampleFixture, RunnerError};
+
+/// Skeleton of a quest module. `DayNN` is replaced by the name of the solver, and the marker
+/// on its own line by the examples.
+const TEMPLATE: &str = r"use std::num::ParseIntError;
+
+use thiserror::Error;
+
+use crate::Day;
+use crate::registry::Example;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub struct DayNN;
+
+impl Day for DayNN {
+    type Input = Vec<u64>;
+    type ParseError = ParseError;
+    type Params = ();
+    /// Add each part here once it is implemented
+    const PARTS: &[u16] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Ok(input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+//EXAMPLES
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn test_examples() {
+        check_examples::<DayNN>(EXAMPLES);
+    }
+}
+";
+
+/// Generates the module of a new quest, with the examples as constants checked by its tests.
+pub fn generate(day: u16, examples: &[ExampleFixture]) -> String {
+    let name = format!("Day{day:02}");
+    // Examples shared between parts get a single constant. Those without an answer are left
+    // out, as nothing would use them.
+    let mut inputs = Vec::<&str>::new();
+    let mut numbered = Vec::new();
+    for example in examples.iter().filter(|example| example.expected.is_some()) {
+        if !inputs.contains(&example.input.as_str()) {
+            inputs.push(&example.input);
+        }
+        let index = inputs.iter().position(|&input| input == example.input);
+        numbered.push((example, index.unwrap_or_default() + 1));
+    }
+
+    let mut consts = String::new();
+    for (input, number) in inputs.iter().zip(1..) {
+        writeln!(
+            consts,
+            "const EXAMPLE{number}: &str = {};",
+            string_literal(input)
+        )
+        .unwrap();
+        consts.push('\n');
+    }
+    consts.push_str("pub const EXAMPLES: &[Example] = &[");
+    for (example, number) in &numbered {
+        if let Some(expected) = &example.expected {
+            write!(
+                consts,
+                "\n    Example::new({}, EXAMPLE{number}, {expected:?}),",
+                example.part
+            )
+            .unwrap();
+        }
+    }
+    consts.push_str(if numbered.is_empty() { "];" } else { "\n];" });
+
+    TEMPLATE
+        .replace("DayNN", &name)
+        .replace("//EXAMPLES", &consts)
+}
+
+/// A string literal in the style of the other examples: one line of the input per source line.
+fn string_literal(input: &str) -> String {
+    // Line continuations would eat leading whitespace.
+    let multiline = input.contains('\n')
+        && !input
+            .lines()
+            .any(|line| line.starts_with([' ', '\t']) || line.is_empty());
+    if !multiline {
+        return format!("{input:?}");
+    }
+    let mut literal = String::from("\"\\\n");
+    let mut lines = input.lines().peekable();
+    while let Some(line) = lines.next() {
+        let escaped = line.replace('\\', "\\\\").replace('"', "\\\"");
+        let end = if lines.peek().is_some() {
+            "\\n\\"
+        } else {
+            "\\"
+        };
+        writeln!(literal, "    {escaped}{end}").unwrap();
+    }
+    literal.push('"');
+    literal
+}
+
+/// Adds the module and the registry entry of a new quest to the source of `lib.rs`.
+/// Returns `None` if the places to add them could not be found.
+pub fn register(lib_rs: &str, day: u16, title: &str) -> Option<String> {
+    let module = format!("day_{day:02}");
+    let mod_line = format!("pub mod {module};\n");
+    // Keep the modules ordered by day.
+    let mod_pos = lib_rs
+        .match_indices("\npub mod day_")
+        .map(|(index, _)| index + 1)
+        .find(|&index| lib_rs[index..] > *mod_line)
+        .or_else(|| {
+            let (last, _) = lib_rs.match_indices("\npub mod day_").last()?;
+            Some(last + 1 + lib_rs[last + 1..].find('\n')? + 1)
+        })?;
+
+    let registry = lib_rs.find("const REGISTRY: Registry")?;
+    let entry_pos = registry + lib_rs[registry..].find("    ],\n};")?;
+    let call = format!(
+        "DayEntry::new::<{module}::Day{day:02}>({day}, {title:?}).with_examples({module}::EXAMPLES),"
+    );
+    let entry = if call.len() + 8 <= 100 {
+        format!("        {call}\n")
+    } else {
+        let (new, examples) = call.split_at(call.find(".with_examples").unwrap());
+        format!("        {new}\n            {examples}\n")
+    };
+
+    let mut result = lib_rs.to_string();
+    result.insert_str(entry_pos, &entry);
+    result.insert_str(mod_pos, &mod_line);
+    Some(result)
+}
+
+/// Writes the new module next to `lib.rs`, and registers it there.
+pub fn create(
+    src_dir: &Path,
+    day: u16,
+    title: &str,
+    examples: &[ExampleFixture],
+) -> Result<bool, RunnerError> {
+    let module_fn = src_dir.join(format!("day_{day:02}.rs"));
+    if module_fn.exists() {
+        eprintln!("{} already exists", module_fn.display());
+        return Ok(false);
+    }
+    let lib_fn = src_dir.join("lib.rs");
+    let lib_rs = std::fs::read_to_string(&lib_fn).map_err(RunnerError::io(&lib_fn))?;
+    let Some(lib_rs) = register(&lib_rs, day, title) else {
+        eprintln!(
+            "Could not find where to register quest {day} in {}",
+            lib_fn.display()
+        );
+        return Ok(false);
+    };
+    std::fs::write(&module_fn, generate(day, examples)).map_err(RunnerError::io(&module_fn))?;
+    eprintln!("Saved {}", module_fn.display());
+    std::fs::write(&lib_fn, lib_rs).map_err(RunnerError::io(&lib_fn))?;
+    eprintln!("Registered quest {day} in {}", lib_fn.display());
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{DayEntry, Registry};
+    use crate::runner::{Event, Runner};
+    use crate::scaffold_sample;
+
+    const LIB_RS: &str = "\
+pub mod day_01;
+pub mod day_03;
+
+pub const REGISTRY: Registry = Registry {
+    event: Event::Main(2025),
+    days: &[
+        DayEntry::new::<day_01::Day01>(1, \"First\").with_examples(day_01::EXAMPLES),
+    ],
+};
+";
+
+    #[test]
+    fn test_register() {
+        let lib_rs = register(LIB_RS, 2, "Second").unwrap();
+        assert!(lib_rs.starts_with("pub mod day_01;\npub mod day_02;\npub mod day_03;\n"));
+        assert!(lib_rs.ends_with(
+            "        DayEntry::new::<day_02::Day02>(2, \"Second\").with_examples(day_02::EXAMPLES),\n    ],\n};\n"
+        ));
+
+        let lib_rs = register(LIB_RS, 4, "A title long enough to wrap the entry").unwrap();
+        assert!(lib_rs.starts_with("pub mod day_01;\npub mod day_03;\npub mod day_04;\n"));
+        assert!(lib_rs.contains(
+            "        DayEntry::new::<day_04::Day04>(4, \"A title long enough to wrap the entry\")\n            .with_examples(day_04::EXAMPLES),\n"
+        ));
+
+        assert_eq!(register("fn main() {}", 2, "Second"), None);
+    }
+
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(string_literal("1,2,3"), "\"1,2,3\"");
+        assert_eq!(
+            string_literal("ab\nc\"d"),
+            "\"\\\n    ab\\n\\\n    c\\\"d\\\n\""
+        );
+        assert_eq!(string_literal(" a\nb"), "\" a\\nb\"");
+    }
+
+    #[test]
+    fn test_generate() {
+        let example = |part, input: &str, expected: Option<&str>| ExampleFixture {
+            part,
+            number: 1,
+            input: input.to_string(),
+            expected: expected.map(String::from),
+        };
+        let module = generate(
+            7,
+            &[
+                example(1, "1,2", Some("3")),
+                example(2, "1,2", Some("2")),
+                example(2, "7", None),
+                example(3, "4\n5", Some("9")),
+            ],
+        );
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const EXAMPLE1: &str = \"1,2\";"));
+        assert!(module.contains("const EXAMPLE2: &str = \"\\\n    4\\n\\\n    5\\\n\";"));
+        assert!(module.contains("    Example::new(2, EXAMPLE1, \"2\"),\n"));
+        assert!(module.contains("    Example::new(3, EXAMPLE2, \"9\"),\n];\n"));
+        // The example without an answer would be an unused constant.
+        assert!(!module.contains("\"7\""));
+        assert!(!module.contains("EXAMPLE3"));
+    }
+
+    /// The sample is compiled and linted as part of the crate, so it has to match exactly.
+    #[test]
+    fn test_generate_sample() {
+        const REGISTRY: Registry = Registry {
+            event: Event::Main(2025),
+            days: &[DayEntry::new::<scaffold_sample::Day21>(21, "Sample")
+                .with_examples(scaffold_sample::EXAMPLES)],
+        };
+        let example = |part, expected: Option<&str>| ExampleFixture {
+            part,
+            number: 1,
+            input: "1,2".to_string(),
+            expected: expected.map(String::from),
+        };
+        let examples = [
+            example(1, Some("3")),
+            example(2, Some("2")),
+            example(3, None),
+        ];
+        assert_eq!(generate(21, &examples), include_str!("scaffold_sample.rs"));
+        let summary = REGISTRY.check(&Runner::default(), None);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.passed, 2);
+        // Registered without any parts, so the examples wait for the solutions.
+        let summary = REGISTRY.run_examples(&Runner::default(), None, None);
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.skipped, 2);
+    }
+}
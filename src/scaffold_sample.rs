@@ -0,0 +1,48 @@
+use std::num::ParseIntError;
+
+use thiserror::Error;
+
+use crate::Day;
+use crate::registry::Example;
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    InvalidNumber(#[from] ParseIntError),
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+    type Input = Vec<u64>;
+    type ParseError = ParseError;
+    type Params = ();
+    /// Add each part here once it is implemented
+    const PARTS: &[u16] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Ok(input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+const EXAMPLE1: &str = "1,2";
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(1, EXAMPLE1, "3"),
+    Example::new(2, EXAMPLE1, "2"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day21>(EXAMPLES);
+    }
+}
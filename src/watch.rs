@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::registry::{DayEntry, Registry};
+use crate::report::PartReport;
+use crate::runner::{Runner, RunnerError, Sampling};
+
+/// An answer shown by a round of the watch mode, of either the input or an example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Outcome {
+    label: String,
+    answer: String,
+    expected: Option<String>,
+}
+
+/// The answer of a report, or else why there is none.
+fn answer_of(report: PartReport) -> String {
+    report.answer.unwrap_or_else(|| match report.error {
+        Some(error) => format!("{} ({error})", report.status.as_str()),
+        None => report.status.as_str().to_string(),
+    })
+}
+
+/// How an answer compares to the one of the previous round.
+fn change(previous: Option<&str>, answer: &str) -> String {
+    match previous {
+        None => String::new(),
+        Some(previous) if previous == answer => " (unchanged)".to_string(),
+        Some(previous) => format!(" (was {previous})"),
+    }
+}
+
+impl DayEntry {
+    /// Runs the selected parts on the example fixtures, and then on the input.
+    fn watch_round(
+        &self,
+        runner: &mut Runner,
+        part_filter: Option<u16>,
+    ) -> Result<Vec<Outcome>, RunnerError> {
+        let mut outcomes = Vec::new();
+        for example in runner.read_examples(self.day) {
+            let part = example.part;
+            if part_filter.is_some_and(|p| p != part) {
+                continue;
+            }
+            let report = self.run_on(runner, part, example.input, Sampling::repeat(1));
+            outcomes.push(Outcome {
+                label: format!("Part {part} - Example {}", example.number),
+                answer: answer_of(report),
+                expected: example.expected,
+            });
+        }
+        for part in 1..=3 {
+            if part_filter.is_some_and(|p| p != part) {
+                continue;
+            }
+            let report = self.run(runner, part, Sampling::repeat(1))?;
+            outcomes.push(Outcome {
+                label: format!("Part {part}"),
+                answer: answer_of(report),
+                expected: runner.known_answer(self.day, part),
+            });
+        }
+        Ok(outcomes)
+    }
+}
+
+impl Registry {
+    /// Re-runs a quest each time one of its inputs or example fixtures changes. Changes to the
+    /// source need a rebuild, such as by running this under `cargo watch`.
+    pub fn watch(
+        &self,
+        runner: &mut Runner,
+        day: u16,
+        part_filter: Option<u16>,
+        interval: Duration,
+    ) -> Result<(), RunnerError> {
+        let Some(entry) = self.get(day) else {
+            println!("No solution for quest {day}");
+            return Ok(());
+        };
+        let mut previous = HashMap::<String, String>::new();
+        loop {
+            for outcome in entry.watch_round(runner, part_filter)? {
+                let Outcome {
+                    label,
+                    answer,
+                    expected,
+                } = outcome;
+                let check = match expected {
+                    Some(expected) if expected == answer => " PASS".to_string(),
+                    Some(expected) => format!(" FAIL (expected {expected})"),
+                    None => String::new(),
+                };
+                let change = change(previous.get(&label).map(String::as_str), &answer);
+                println!("Quest {day} - {label}: {answer}{check}{change}");
+                previous.insert(label, answer);
+            }
+            println!();
+            eprintln!("Watching for changes...");
+            // Taken after the round, which may have downloaded the input and the answers.
+            let files = runner.day_files(day);
+            while runner.day_files(day) == files {
+                std::thread::sleep(interval);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::Day03;
+    use crate::runner::Event;
+    use crate::runner::tests::{UNREACHABLE_URL, temp_input_root};
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(None, "29"), "");
+        assert_eq!(change(Some("29"), "29"), " (unchanged)");
+        assert_eq!(change(Some("28"), "29"), " (was 28)");
+    }
+
+    #[test]
+    fn test_watch_round() {
+        let input_root = temp_input_root("watch-round");
+        let event_dir = input_root.join("2025");
+        std::fs::create_dir_all(&event_dir).unwrap();
+        std::fs::write(event_dir.join("day_03_part_1_example_1.txt"), "10,5,1").unwrap();
+        std::fs::write(event_dir.join("day_03_part_1_example_1_answer.txt"), "16").unwrap();
+        std::fs::write(event_dir.join("day_03_part_1.txt"), "1,2,2").unwrap();
+        // Where the 2025 inputs used to be
+        std::fs::write(input_root.join("day_03_part_2.txt"), "1,2").unwrap();
+        let mut runner = Runner::new(Event::Main(2025), UNREACHABLE_URL, &input_root);
+        runner.set_offline(true);
+        let entry = DayEntry::new::<Day03>(3, "The Deepest Fit");
+
+        let files = runner.day_files(3);
+        assert_eq!(files.len(), 4);
+        let outcomes = entry.watch_round(&mut runner, Some(1)).unwrap();
+        assert_eq!(
+            outcomes,
+            [
+                Outcome {
+                    label: "Part 1 - Example 1".to_string(),
+                    answer: "16".to_string(),
+                    expected: Some("16".to_string()),
+                },
+                Outcome {
+                    label: "Part 1".to_string(),
+                    answer: "3".to_string(),
+                    expected: None,
+                },
+            ]
+        );
+
+        std::fs::write(event_dir.join("day_03_part_1.txt"), "1,2,2,3").unwrap();
+        assert_ne!(runner.day_files(3), files);
+        let files = runner.day_files(3);
+        std::fs::write(input_root.join("day_03_part_2.txt"), "1,2,3").unwrap();
+        assert_ne!(runner.day_files(3), files);
+        std::fs::remove_dir_all(input_root).unwrap();
+    }
+}